@@ -2,11 +2,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod lich;
+#[cfg(feature = "std")]
+mod maybe;
 mod shroud;
+mod sink;
 mod soul;
 mod sync;
+#[cfg(feature = "std")]
+pub mod util;
 
-pub use lich::Lich;
+#[cfg(feature = "std")]
+pub use lich::AnyLich;
+pub use lich::{HookedLich, Lich};
+#[cfg(feature = "std")]
+pub use maybe::MaybeLich;
 /// A convenience macro to implement the [`Shroud<T>`] trait for a given trait.
 /// The macro is applied to a trait directly because it will derive blanket
 /// implementations of [`Shroud<T>`] for all `T: Trait`. It can also handle
@@ -14,6 +23,11 @@ pub use lich::Lich;
 /// [`Sync`], and [`Unpin`] (e.g., `dyn Trait + Send`, `dyn Trait + Sync`, `dyn
 /// Trait + Send + Unpin`, etc.).
 ///
+/// For discoverability, each generated trait object type also gets an inherent
+/// `shroud_from` function that forwards to [`Shroud::shroud`], such that
+/// `<dyn Trait>::shroud_from(pointer)` can be called without naming
+/// [`Shroud<T>`].
+///
 /// # Usage
 ///
 /// ```
@@ -54,7 +68,10 @@ pub use lich::Lich;
 #[cfg(feature = "shroud")]
 pub use phylactery_macro::shroud;
 pub use shroud::Shroud;
-pub use soul::Soul;
+pub use sink::PushSink;
+#[cfg(feature = "std")]
+pub use soul::NonBlockingSoul;
+pub use soul::{Reservation, ScopedLich, SeverOutcome, Soul};
 
 #[allow(dead_code)]
 mod fails {
@@ -94,6 +111,37 @@ mod fails {
         spawn(move || lich());
     });
 
+    fail!(can_not_send_unsend_to_thread, {
+        use core::pin::pin;
+        use phylactery::Soul;
+        use std::thread::spawn;
+
+        let soul = pin!(Soul::new(|| {}));
+        let lich = soul.as_ref().bind::<dyn Fn()>();
+        spawn(move || lich());
+    });
+
+    fail!(can_not_return_scoped_lich_from_scope, {
+        use core::pin::pin;
+        use phylactery::{ScopedLich, Soul};
+
+        fn scoped() -> ScopedLich<'static, dyn Fn()> {
+            let soul = pin!(Soul::new(|| {}));
+            soul.as_ref().bind_scoped::<dyn Fn()>()
+        }
+    });
+
+    fail!(can_not_bind_unshrouded_trait, {
+        use core::pin::pin;
+        use phylactery::Soul;
+
+        trait Unshrouded {}
+        impl Unshrouded for () {}
+
+        let soul = pin!(Soul::new(()));
+        let lich = soul.as_ref().bind::<dyn Unshrouded>();
+    });
+
     // Issue 14: Lich<dyn FnOnce()> cannot be called through Deref (&T).
     // Binding compiles; calling fails because FnOnce requires owning self.
     fail!(can_not_call_lich_dyn_fnonce, {
@@ -3,9 +3,9 @@ use crate::sync::{self, AtomicU32, Ordering};
 use core::{
     borrow::Borrow,
     fmt,
-    mem::forget,
+    mem::{ManuallyDrop, forget},
     ops::Deref,
-    ptr::NonNull,
+    ptr::{self, NonNull},
 };
 
 /// A [`Lich`] acts like a `&'static T`, but its validity is dynamically tied to
@@ -31,7 +31,20 @@ use core::{
 /// [`Soul`](crate::soul::Soul)'s data.
 pub struct Lich<T: ?Sized> {
     pub(crate) value: NonNull<T>,
-    pub(crate) count: NonNull<AtomicU32>,
+    pub(crate) count: NonNull<Count>,
+}
+
+/// The binding count shared by a [`Soul`](crate::soul::Soul) and its
+/// [`Lich`]es.
+#[derive(Default)]
+pub(crate) struct Count {
+    pub(crate) value: AtomicU32,
+    /// The number of threads parked in
+    /// [`Soul::bind_bounded`](crate::soul::Soul::bind_bounded).
+    #[cfg(feature = "bounded")]
+    pub(crate) waiters: AtomicU32,
+    #[cfg(feature = "high-water")]
+    pub(crate) peak: AtomicU32,
 }
 
 unsafe impl<T: ?Sized> Send for Lich<T> where for<'a> &'a T: Send {}
@@ -45,11 +58,73 @@ impl<T: ?Sized> Lich<T> {
     /// [`Soul`](crate::soul::Soul) has already been severed.
     #[must_use]
     pub fn bindings(&self) -> usize {
-        let raw = self.count_ref().load(Ordering::Relaxed);
+        let raw = self.count_ref().value.load(Ordering::Relaxed);
         // `SEVERED` (`u32::MAX`) is the severed sentinel; treat it as 0 live bindings.
         raw.wrapping_add(1).saturating_sub(1) as _
     }
 
+    /// Returns `true` if both [`Lich`]es point to the same value.
+    ///
+    /// The comparison includes the pointer metadata (such as the vtable of a
+    /// trait object), such that the same value shrouded with different vtables
+    /// is not considered equal. This is distinct from
+    /// [`Soul::is_bound`](crate::soul::Soul::is_bound), which compares the
+    /// binding rather than the value.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        ptr::eq(self.value.as_ptr(), other.value.as_ptr())
+    }
+
+    /// Binds a new [`Lich`] to a value projected from this [`Lich`]'s value,
+    /// such as one of its fields or the `str` of a `String`.
+    ///
+    /// The projected [`Lich`] is bound to the same
+    /// [`Soul`](crate::soul::Soul) as this one. Since `project` must return a
+    /// reference derived from its argument, the projected value lives as long
+    /// as the [`Soul`](crate::soul::Soul)'s value.
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn project<U: ?Sized, F: FnOnce(&T) -> &U>(&self, project: F) -> Lich<U> {
+        let value = NonNull::from(project(self.data_ref()));
+        increment(self.count_ref(), 1);
+        Lich {
+            value,
+            count: self.count,
+        }
+    }
+
+    /// Borrows a value projected from this [`Lich`]'s value, such as one of its
+    /// fields.
+    ///
+    /// Unlike [`project()`](Lich::project), this does not bind a new [`Lich`];
+    /// the returned reference is tied to the borrow of this [`Lich`].
+    pub fn map<U: ?Sized, F: FnOnce(&T) -> &U>(&self, map: F) -> &U {
+        map(self.data_ref())
+    }
+
+    /// Returns a [`fmt::Debug`] view of this [`Lich`]'s binding rather than of
+    /// its value, formatted as `Lich { value: 0x..., bindings: 3 }`.
+    ///
+    /// The [`fmt::Debug`] implementation of [`Lich`] forwards to the value.
+    #[must_use]
+    pub fn debug_binding(&self) -> impl fmt::Debug + '_ {
+        DebugBinding(self)
+    }
+
+    /// Attaches a `hook` to this [`Lich`] that is called exactly once when the
+    /// returned [`HookedLich`] is dropped, right before the [`Lich`] is
+    /// redeemed.
+    ///
+    /// The hook belongs to this [`Lich`] only: a [`HookedLich`] does not
+    /// implement [`Clone`] and the [`Lich`]es cloned through
+    /// [`HookedLich::lich`] have no hook.
+    #[must_use = "the hook is immediately called if not used"]
+    pub fn with_drop_hook<F: FnOnce()>(self, hook: F) -> HookedLich<T, F> {
+        HookedLich {
+            lich: ManuallyDrop::new(self),
+            hook: ManuallyDrop::new(hook),
+        }
+    }
+
     /// Disposes of this [`Lich`], decrementing the binding count for its
     /// parent [`Soul`](crate::soul::Soul).
     ///
@@ -69,18 +144,64 @@ impl<T: ?Sized> Lich<T> {
         count
     }
 
+    /// Creates `count` clones of this [`Lich`].
+    ///
+    /// This is equivalent to calling [`Clone::clone`] `count` times, except
+    /// that the binding count is incremented by `count` in a single atomic
+    /// operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of [`Lich`]es would be exceeded.
+    #[cfg(feature = "std")]
+    #[must_use = "the Liches are immediately dropped if not used"]
+    pub fn clone_many(&self, count: usize) -> Vec<Self> {
+        // A `count` that does not fit in a `u32` can only exceed the maximum.
+        increment(self.count_ref(), u32::try_from(count).unwrap_or(u32::MAX));
+        (0..count)
+            .map(|_| Self {
+                value: self.value,
+                count: self.count,
+            })
+            .collect()
+    }
+
+    /// Boxes this [`Lich`] as a type-erased [`Any`](core::any::Any) such that it
+    /// can be stored in dynamically typed containers.
+    ///
+    /// The [`Lich`] stays bound while boxed: dropping the box drops the
+    /// [`Lich`]. Use [`from_any()`](Lich::from_any) to recover it.
+    #[cfg(feature = "std")]
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn into_any(self) -> Box<dyn core::any::Any + Send>
+    where
+        T: 'static,
+        Self: Send,
+    {
+        Box::new(self)
+    }
+
+    /// Recovers a [`Lich`] boxed with [`into_any()`](Lich::into_any).
+    ///
+    /// If `any` does not hold a `Lich<T>`, it is returned back.
+    #[cfg(feature = "std")]
+    #[must_use = "if Err, the box is returned back"]
+    pub fn from_any(
+        any: Box<dyn core::any::Any + Send>,
+    ) -> Result<Self, Box<dyn core::any::Any + Send>>
+    where
+        T: 'static,
+    {
+        any.downcast::<Self>().map(|lich| *lich)
+    }
+
     /// Safety: must be called only once for this `Lich` when it became
     /// unreachable.
     unsafe fn redeem_unchecked(&self) -> usize {
-        let count = self.count_ref();
-        let remain = decrement(count);
-        if remain == 0 {
-            sync::wake_all(count);
-        }
-        remain as _
+        decrement(self.count_ref(), 1) as _
     }
 
-    fn count_ref(&self) -> &AtomicU32 {
+    fn count_ref(&self) -> &Count {
         // Safety: the pointers are valid for the lifetime of `self`; guaranteed by the
         // reference count.
         unsafe { self.count.as_ref() }
@@ -93,9 +214,72 @@ impl<T: ?Sized> Lich<T> {
     }
 }
 
+macro_rules! downcast {
+    ($($any: ty),*) => {$(
+        impl Lich<$any> {
+            /// Attempts to convert this type-erased [`Lich`] into a [`Lich`] of the
+            /// concrete type `U`.
+            ///
+            /// The binding is transferred to the returned [`Lich`] such that the
+            /// binding count is unchanged. If the value is not of type `U`, this
+            /// [`Lich`] is returned back. To only borrow the concrete value, use
+            /// `downcast_ref` through [`Deref`].
+            #[must_use = "if Err, the Lich is returned back"]
+            pub fn downcast<U: core::any::Any>(self) -> Result<Lich<U>, Self> {
+                if self.data_ref().is::<U>() {
+                    let lich = Lich {
+                        value: self.value.cast::<U>(),
+                        count: self.count,
+                    };
+                    forget(self);
+                    Ok(lich)
+                } else {
+                    Err(self)
+                }
+            }
+        }
+    )*};
+}
+
+downcast!(
+    dyn core::any::Any,
+    dyn core::any::Any + Send,
+    dyn core::any::Any + Send + Sync
+);
+
+impl Count {
+    #[cfg(not(loom))]
+    pub(crate) const fn new() -> Self {
+        Self {
+            value: AtomicU32::new(0),
+            #[cfg(feature = "bounded")]
+            waiters: AtomicU32::new(0),
+            #[cfg(feature = "high-water")]
+            peak: AtomicU32::new(0),
+        }
+    }
+
+    #[cfg(loom)]
+    pub(crate) fn new() -> Self {
+        Self {
+            value: AtomicU32::new(0),
+            #[cfg(feature = "bounded")]
+            waiters: AtomicU32::new(0),
+            #[cfg(feature = "high-water")]
+            peak: AtomicU32::new(0),
+        }
+    }
+}
+
+impl fmt::Debug for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
 impl<T: ?Sized> Clone for Lich<T> {
     fn clone(&self) -> Self {
-        increment(self.count_ref());
+        increment(self.count_ref(), 1);
         Self {
             value: self.value,
             count: self.count,
@@ -124,10 +308,18 @@ impl<T: ?Sized> AsRef<T> for Lich<T> {
 }
 
 impl<T: fmt::Debug + ?Sized> fmt::Debug for Lich<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.data_ref(), f)
+    }
+}
+
+struct DebugBinding<'a, T: ?Sized>(&'a Lich<T>);
+
+impl<T: ?Sized> fmt::Debug for DebugBinding<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Lich")
-            .field("value", &self.data_ref())
-            .field("bindings", &self.bindings())
+            .field("value", &self.0.value.as_ptr().cast::<()>())
+            .field("bindings", &self.0.bindings())
             .finish()
     }
 }
@@ -178,16 +370,108 @@ impl<T: ?Sized> Drop for Lich<T> {
     }
 }
 
-pub(crate) fn increment(count: &AtomicU32) -> u32 {
-    let result = count.fetch_update(Ordering::Acquire, Ordering::Relaxed, |value| {
-        if value < SEVERED - 1 {
-            Some(value + 1)
-        } else {
-            None
+/// A [`Lich`] with a hook that is called when it is dropped, created with
+/// [`Lich::with_drop_hook`].
+pub struct HookedLich<T: ?Sized, F: FnOnce()> {
+    lich: ManuallyDrop<Lich<T>>,
+    hook: ManuallyDrop<F>,
+}
+
+impl<T: ?Sized, F: FnOnce()> HookedLich<T, F> {
+    /// Returns the hooked [`Lich`].
+    ///
+    /// Cloning it produces a [`Lich`] without the hook.
+    #[must_use]
+    pub fn lich(&self) -> &Lich<T> {
+        &self.lich
+    }
+
+    /// Detaches the hook without calling it and returns it along with the
+    /// [`Lich`].
+    pub fn unhook(self) -> (Lich<T>, F) {
+        let mut this = ManuallyDrop::new(self);
+        // Safety: `this` is never dropped, such that each field is taken exactly once.
+        unsafe {
+            (
+                ManuallyDrop::take(&mut this.lich),
+                ManuallyDrop::take(&mut this.hook),
+            )
         }
-    });
+    }
+}
+
+impl<T: ?Sized, F: FnOnce()> Deref for HookedLich<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lich
+    }
+}
+
+impl<T: fmt::Debug + ?Sized, F: FnOnce()> fmt::Debug for HookedLich<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self.lich, f)
+    }
+}
+
+impl<T: ?Sized, F: FnOnce()> Drop for HookedLich<T, F> {
+    fn drop(&mut self) {
+        // Safety: the fields are taken exactly once, in `drop`. The `Lich` is dropped
+        // even if the hook panics.
+        let lich = unsafe { ManuallyDrop::take(&mut self.lich) };
+        let hook = unsafe { ManuallyDrop::take(&mut self.hook) };
+        hook();
+        drop(lich);
+    }
+}
+
+/// A [`Lich`] with its value type erased.
+///
+/// This object-safe trait allows [`Lich`]es of different types to be stored
+/// together, for example in a `Vec<Box<dyn AnyLich + Send>>`, and redeemed
+/// without knowing their value type.
+#[cfg(feature = "std")]
+pub trait AnyLich {
+    /// Returns the number of [`Lich`]es that are currently bound to the
+    /// [`Soul`](crate::soul::Soul) of this [`Lich`].
+    ///
+    /// See [`Lich::bindings`].
+    fn bindings(&self) -> usize;
+
+    /// Redeems this [`Lich`] and returns the number of [`Lich`]es still bound
+    /// to its [`Soul`](crate::soul::Soul).
+    ///
+    /// See [`Lich::redeem`].
+    fn redeem(self: Box<Self>) -> usize;
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> AnyLich for Lich<T> {
+    fn bindings(&self) -> usize {
+        Lich::bindings(self)
+    }
+
+    fn redeem(self: Box<Self>) -> usize {
+        Lich::redeem(*self)
+    }
+}
+
+pub(crate) fn increment(count: &Count, add: u32) -> u32 {
+    increment_ordered(count, add, Ordering::Acquire)
+}
+
+pub(crate) fn increment_ordered(count: &Count, add: u32, order: Ordering) -> u32 {
+    let next = |value: u32| match value.checked_add(add) {
+        Some(value) if value < SEVERED => Some(value),
+        _ => None,
+    };
+    let result = count.value.fetch_update(order, Ordering::Relaxed, next);
     match result {
-        Ok(value) => value,
+        Ok(value) => {
+            #[cfg(feature = "high-water")]
+            count.peak.fetch_max(value + add, Ordering::Relaxed);
+            value
+        }
         // `Err(SEVERED)` means `sever` has already been called. `bind` requires a
         // `Pin<&Self>` which is impossible to hold after `sever` consumes the Pin,
         // so this branch is unreachable in safe code.
@@ -196,9 +480,59 @@ pub(crate) fn increment(count: &AtomicU32) -> u32 {
     }
 }
 
-pub(crate) fn decrement(count: &AtomicU32) -> u32 {
-    match count.fetch_sub(1, Ordering::Release) {
-        0 | SEVERED => unreachable!(),
-        value => value - 1,
+#[cfg(feature = "bounded")]
+pub(crate) fn increment_bounded(count: &Count, max: u32) -> u32 {
+    assert!(max > 0, "a `Soul` can not be bound with a maximum of 0");
+    let max = max.min(SEVERED - 1);
+    loop {
+        match count.value.load(Ordering::Relaxed) {
+            // `SEVERED` is always at least `max` and is never decremented, so it must be
+            // checked first to panic like `increment` instead of waiting forever.
+            SEVERED => unreachable!("bind called on a severed Soul"),
+            value if value < max => {
+                let next = value + 1;
+                let exchange = count.value.compare_exchange_weak(
+                    value,
+                    next,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                );
+                if exchange.is_ok() {
+                    #[cfg(feature = "high-water")]
+                    count.peak.fetch_max(next, Ordering::Relaxed);
+                    break value;
+                }
+            }
+            value => {
+                // `SeqCst` pairs with `decrement` such that either this waiter is observed
+                // and woken, or the decremented value is observed by `wait`.
+                count.waiters.fetch_add(1, Ordering::SeqCst);
+                sync::wait(&count.value, value);
+                count.waiters.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Decrements the count by `sub` and wakes the threads that wait on it if it
+/// reached 0 or, with the `bounded` feature, if a thread is parked in
+/// [`Soul::bind_bounded`](crate::soul::Soul::bind_bounded).
+pub(crate) fn decrement(count: &Count, sub: u32) -> u32 {
+    #[cfg(not(feature = "bounded"))]
+    let order = Ordering::Release;
+    #[cfg(feature = "bounded")]
+    let order = Ordering::SeqCst;
+    let remain = match count.value.fetch_sub(sub, order) {
+        SEVERED => unreachable!(),
+        value if value < sub => unreachable!(),
+        value => value - sub,
+    };
+    #[cfg(not(feature = "bounded"))]
+    let wake = remain == 0;
+    #[cfg(feature = "bounded")]
+    let wake = remain == 0 || count.waiters.load(Ordering::SeqCst) > 0;
+    if wake {
+        sync::wake_all(&count.value);
     }
+    remain
 }
@@ -0,0 +1,65 @@
+use crate::lich::Lich;
+use core::{borrow::Borrow, ops::Deref};
+
+/// Either a [`Lich`] that extends the lifetime of a borrowed value or a
+/// [`Box`] that owns its value.
+///
+/// A [`MaybeLich`] allows an API to accept both a reference that is promised
+/// to outlive its uses and an owned value behind a single type. In both cases,
+/// it dereferences to the underlying value.
+#[derive(Debug)]
+pub enum MaybeLich<T: ?Sized> {
+    /// A value borrowed from a [`Soul`](crate::soul::Soul).
+    Lich(Lich<T>),
+    /// An owned value.
+    Owned(Box<T>),
+}
+
+impl<T: ?Sized> MaybeLich<T> {
+    /// Returns `true` if the value is borrowed through a [`Lich`].
+    #[must_use]
+    pub const fn is_lich(&self) -> bool {
+        matches!(self, Self::Lich(_))
+    }
+
+    /// Returns `true` if the value is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<T: ?Sized> From<Lich<T>> for MaybeLich<T> {
+    fn from(lich: Lich<T>) -> Self {
+        Self::Lich(lich)
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for MaybeLich<T> {
+    fn from(value: Box<T>) -> Self {
+        Self::Owned(value)
+    }
+}
+
+impl<T: ?Sized> Deref for MaybeLich<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Lich(lich) => lich,
+            Self::Owned(value) => value,
+        }
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for MaybeLich<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for MaybeLich<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
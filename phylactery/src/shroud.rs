@@ -4,13 +4,75 @@ use core::ptr::NonNull;
 /// dynamic trait pointer.
 ///
 /// Note that it is already implemented for `Fn(T0, .., T7) -> T` and its
-/// combinations with [`Send`], [`Sync`] and [`Unpin`].
+/// combinations with [`Send`], [`Sync`] and [`Unpin`]. Closures with a
+/// higher-ranked signature, such as `dyn Fn(&T0) -> T`, are not supported
+/// since their implementations would overlap with those of `Fn(T0) -> T`.
+///
+/// Primitive types, arrays, slices and, with the `std` feature, `String` and
+/// `Vec<T>` can also be shrouded as themselves, which passes the data pointer
+/// through unchanged. Other types can do the same by implementing
+/// `Shroud<Self>` with a `shroud` that returns its argument. A `Lich<str>` is
+/// obtained with [`Lich::project`](crate::Lich::project) instead, such as from
+/// a `Lich<String>`.
 ///
 /// See the [`shroud`](crate::shroud) macro for convenient implementation.
+#[rustversion::attr(
+    since(1.78),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `Shroud<{T}>`",
+        label = "`{T}` can not be shrouded as `{Self}`",
+        note = "if `{Self}` is a trait object, did you add `#[shroud]` to its trait?"
+    )
+)]
 pub trait Shroud<T: ?Sized> {
     fn shroud(from: NonNull<T>) -> NonNull<Self>;
 }
 
+/// Implements [`Shroud`] for types as themselves, which passes the data
+/// pointer through unchanged. Only types that downstream crates can not
+/// implement [`Shroud`] for themselves are listed, such that these
+/// implementations never overlap with theirs.
+macro_rules! shroud_identity {
+    ($([$($generic: tt)*] $type: ty),* $(,)?) => {
+        $(
+            impl<$($generic)*> Shroud<$type> for $type {
+                #[inline(always)]
+                fn shroud(from: NonNull<$type>) -> NonNull<Self> {
+                    from
+                }
+            }
+        )*
+    };
+}
+
+shroud_identity!(
+    [] (),
+    [] bool,
+    [] char,
+    [] f32,
+    [] f64,
+    [] i8,
+    [] i16,
+    [] i32,
+    [] i64,
+    [] i128,
+    [] isize,
+    [] u8,
+    [] u16,
+    [] u32,
+    [] u64,
+    [] u128,
+    [] usize,
+    [T] [T],
+    [T, const N: usize] [T; N],
+);
+
+#[cfg(feature = "std")]
+shroud_identity!(
+    [] std::string::String,
+    [T] std::vec::Vec<T>,
+);
+
 #[cfg(feature = "shroud")]
 mod implement {
     macro_rules! shroud_ty {
@@ -142,6 +204,7 @@ mod implement {
     shroud_ty! { use: ::core::marker::Unpin, trait: Unpin, generics: (), bounds: (), associates: (), dynamic: true, traits: () }
     shroud_ty! { use: ::core::panic::RefUnwindSafe, trait: RefUnwindSafe, generics: (), bounds: (), associates: (), dynamic: true }
     shroud_ty! { use: ::core::panic::UnwindSafe, trait: UnwindSafe, generics: (), bounds: (), associates: (), dynamic: true }
+    shroud_ty! { use: crate::sink::PushSink, trait: PushSink, generics: (), bounds: (T: Sized), associates: (), dynamic: true }
     shroud_ty! { use: ::core::slice::SliceIndex, trait: SliceIndex, generics: (T), bounds: (), associates: (Output), dynamic: true }
 
     const _: () = {
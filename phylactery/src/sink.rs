@@ -0,0 +1,38 @@
+use core::{cell::RefCell, iter::once};
+
+/// An object-safe subset of [`Extend`] that pushes one item at a time through
+/// a shared reference.
+///
+/// [`Extend`] can not be used as a trait object since its methods are generic,
+/// and it requires a `&mut` access that a [`Lich`](crate::Lich) can not
+/// provide. [`PushSink`] is implemented for collections wrapped in a
+/// [`RefCell`] or, with the `std` feature, in a [`Mutex`](std::sync::Mutex),
+/// such that a borrowed collection can be shrouded as a `dyn PushSink<T>` and
+/// extended from another context.
+pub trait PushSink<T> {
+    /// Pushes an `item` into this sink.
+    fn push(&self, item: T);
+}
+
+impl<T, E: Extend<T> + ?Sized> PushSink<T> for RefCell<E> {
+    /// # Panics
+    ///
+    /// Panics if the collection is currently borrowed.
+    #[inline]
+    fn push(&self, item: T) {
+        self.borrow_mut().extend(once(item));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E: Extend<T> + ?Sized> PushSink<T> for std::sync::Mutex<E> {
+    /// Pushes an `item` into the collection once the lock is acquired. A
+    /// poisoned lock is recovered since pushing an item does not rely on the
+    /// collection's invariants beyond those of [`Extend`].
+    #[inline]
+    fn push(&self, item: T) {
+        self.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .extend(once(item));
+    }
+}
@@ -1,11 +1,16 @@
+#[cfg(feature = "bounded")]
+use crate::lich::increment_bounded;
+#[cfg(any(debug_assertions, feature = "hardened"))]
+use crate::sync::AtomicUsize;
 use crate::{
-    lich::{Lich, increment},
+    lich::{Count, Lich, decrement, increment, increment_ordered},
     shroud::Shroud,
     sync::{self, AtomicU32, Ordering},
 };
 use core::{
     borrow::Borrow,
-    marker::PhantomPinned,
+    fmt,
+    marker::{PhantomData, PhantomPinned},
     mem::ManuallyDrop,
     ops::Deref,
     pin::Pin,
@@ -43,6 +48,12 @@ pub(crate) const SEVERED: u32 = u32::MAX;
 /// [`Box::pin`]/[`Arc::pin`](std::sync::Arc::pin)/
 /// [`Rc::pin`](std::rc::Rc::pin).
 ///
+/// In debug builds, binding a [`Soul`] panics if it moved since its first
+/// binding, which can only happen if `unsafe` code broke the pinning
+/// guarantee. The `hardened` feature keeps this check and the other internal
+/// safety checks in release builds, trading a small cost for a panic instead
+/// of undefined behavior.
+///
 /// # Dropping
 ///
 /// The [`Drop`] implementation of [`Soul`] is its core safety feature. If a
@@ -50,10 +61,32 @@ pub(crate) const SEVERED: u32 = u32::MAX;
 /// implementation will block the current thread until all [`Lich`]es are
 /// dropped. This behavior guarantees that no [`Lich`] can ever outlive the data
 /// it points to.
-#[derive(Debug, Default)]
+///
+/// # Unsized Values
+///
+/// A [`Soul`] can not be created directly from an unsized value, but a
+/// heap-pinned [`Soul`] coerces to an unsized one just like a [`Box`] does.
+/// This allows a [`Soul`] to own a trait object or a slice:
+///
+/// ```
+/// use core::pin::Pin;
+/// use phylactery::Soul;
+///
+/// let soul: Pin<Box<Soul<dyn Fn() -> u8>>> = Box::pin(Soul::new(|| 42));
+/// let lich = soul.as_ref().bind::<dyn Fn() -> u8>();
+/// assert_eq!(lich(), 42);
+///
+/// let soul: Pin<Box<Soul<[u8]>>> = Box::pin(Soul::new([1, 2, 3]));
+/// assert_eq!(soul.len(), 3);
+/// ```
+#[derive(Default)]
 pub struct Soul<T: ?Sized> {
     _marker: PhantomPinned,
-    count: AtomicU32,
+    count: Count,
+    /// The address of `count` at the first binding, used to detect a `Soul`
+    /// that moved while it should have been pinned.
+    #[cfg(any(debug_assertions, feature = "hardened"))]
+    origin: AtomicUsize,
     value: T,
 }
 
@@ -62,7 +95,9 @@ impl<T> Soul<T> {
     pub const fn new(value: T) -> Self {
         Self {
             value,
-            count: AtomicU32::new(0),
+            count: Count::new(),
+            #[cfg(any(debug_assertions, feature = "hardened"))]
+            origin: AtomicUsize::new(0),
             _marker: PhantomPinned,
         }
     }
@@ -71,11 +106,38 @@ impl<T> Soul<T> {
     pub fn new(value: T) -> Self {
         Self {
             value,
-            count: AtomicU32::new(0),
+            count: Count::new(),
+            #[cfg(any(debug_assertions, feature = "hardened"))]
+            origin: AtomicUsize::new(0),
             _marker: PhantomPinned,
         }
     }
 
+    /// Creates a [`NonBlockingSoul`] whose [`Drop`] never blocks.
+    ///
+    /// When a [`NonBlockingSoul`] is dropped while [`Lich`]es are still bound
+    /// to it, an error is written to the standard error and the process is
+    /// aborted with [`std::process::abort`] instead of blocking the current
+    /// thread until the [`Lich`]es are dropped.
+    ///
+    /// This removes the blocking safety net of [`Soul`] in exchange for
+    /// predictable, non-blocking teardown. Use it only when the [`Lich`]es are
+    /// known, by other means, to be gone before the [`Soul`] is dropped. Note
+    /// that merely logging and returning is not an option: the remaining
+    /// [`Lich`]es would then point to freed memory.
+    #[cfg(all(feature = "std", not(loom)))]
+    pub const fn new_nonblocking(value: T) -> NonBlockingSoul<T> {
+        NonBlockingSoul(Self::new(value))
+    }
+
+    /// Creates a [`NonBlockingSoul`] whose [`Drop`] never blocks.
+    ///
+    /// See the non-`loom` version of this constructor.
+    #[cfg(all(feature = "std", loom))]
+    pub fn new_nonblocking(value: T) -> NonBlockingSoul<T> {
+        NonBlockingSoul(Self::new(value))
+    }
+
     /// Consumes the [`Soul`] and returns the owned value.
     #[must_use = "discarding the value drops it silently"]
     pub fn into_value(self) -> T {
@@ -83,28 +145,194 @@ impl<T> Soul<T> {
         // the fact that this `Soul` is unpinned.
         unsafe { read(&ManuallyDrop::new(self).value) }
     }
+
+    /// Consumes a pinned [`Soul`] and returns the owned value if no [`Lich`] is
+    /// bound to it.
+    ///
+    /// This is the pinned analog of [`into_value()`](Soul::into_value). It does
+    /// not block; if [`Lich`]es are still bound, the pinned [`Soul`] is returned
+    /// back.
+    #[cfg(feature = "std")]
+    #[must_use = "if Err, the Soul has not been consumed"]
+    pub fn try_into_value(this: Pin<Box<Self>>) -> Result<T, Pin<Box<Self>>> {
+        Self::try_sever(this).map(|soul| soul.into_value())
+    }
+
+    /// Severs all bindings to a pinned [`Soul`], blocking the current thread
+    /// until all bound [`Lich`]es are dropped, then returns the owned value.
+    ///
+    /// This is [`sever()`](Soul::sever) followed by
+    /// [`into_value()`](Soul::into_value). Use
+    /// [`try_into_value()`](Soul::try_into_value) if blocking is not
+    /// acceptable.
+    ///
+    /// # Deadlock
+    ///
+    /// If the calling thread holds the last [`Lich`] that keeps the count
+    /// non-zero, calling this method will deadlock.
+    #[cfg(feature = "std")]
+    #[must_use = "discarding the value drops it silently"]
+    pub fn sever_into_value(this: Pin<Box<Self>>) -> T {
+        Self::sever(this).into_value()
+    }
 }
 
 impl<T: ?Sized> Soul<T> {
+    /// Borrows a pinned [`Soul`] from its pinning container (such as a
+    /// `Pin<Box<Soul<T>>>`, `Pin<Arc<Soul<T>>>` or `Pin<&mut Soul<T>>`).
+    ///
+    /// This is equivalent to [`Pin::as_ref`] and is the form expected by
+    /// [`bind()`](Soul::bind).
+    #[must_use]
+    pub fn pinned<S: Deref<Target = Self>>(this: &Pin<S>) -> Pin<&Self> {
+        this.as_ref()
+    }
+
     /// Binds a new [`Lich`] to this [`Soul`].
     ///
     /// This method can only be called on a pinned [`Soul`], to guarantee that
     /// the [`Soul`]'s memory location is fixed.
     #[must_use = "the Lich is immediately dropped if not used"]
     pub fn bind<S: Shroud<T> + ?Sized>(self: Pin<&Self>) -> Lich<S> {
-        increment(&self.count);
+        #[cfg(any(debug_assertions, feature = "hardened"))]
+        self.assert_unmoved();
+        increment(&self.count, 1);
         Lich {
             count: self.count_ptr(),
             value: S::shroud(self.value_ptr()),
         }
     }
 
+    /// Binds a new [`ScopedLich`] to this [`Soul`] that can not outlive the
+    /// borrow `'a` of the [`Soul`].
+    ///
+    /// This trades the `'static` flexibility of a [`Lich`] for a compile-time
+    /// guarantee that the binding stays within the current scope, for sharing
+    /// that does not need to cross a `'static` boundary.
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn bind_scoped<'a, S: Shroud<T> + ?Sized>(self: Pin<&'a Self>) -> ScopedLich<'a, S> {
+        ScopedLich {
+            lich: self.bind(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Binds a new [`Lich`] to this [`Soul`], incrementing the binding count
+    /// with the given `order`.
+    ///
+    /// [`bind()`](Soul::bind) uses [`Ordering::Acquire`], which synchronizes
+    /// with the [`Lich`]es that were dropped before. With
+    /// [`Ordering::Release`] or [`Ordering::AcqRel`], the writes made by this
+    /// thread before binding become visible to a thread that observes the
+    /// incremented count with an acquiring load, such as
+    /// [`bindings_with(Ordering::Acquire)`](Soul::bindings_with). A release
+    /// increment that is never observed by an acquiring load establishes no
+    /// synchronization.
+    ///
+    /// Dropping a [`Lich`] decrements the count with [`Ordering::Release`],
+    /// such that an acquiring increment synchronizes with the drops that came
+    /// before it. With the `bounded` feature, the decrement uses
+    /// [`Ordering::SeqCst`] instead, which provides the same pairing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of [`Lich`]es would be exceeded.
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn bind_ordered<S: Shroud<T> + ?Sized>(self: Pin<&Self>, order: Ordering) -> Lich<S> {
+        #[cfg(any(debug_assertions, feature = "hardened"))]
+        self.assert_unmoved();
+        increment_ordered(&self.count, 1, order);
+        Lich {
+            count: self.count_ptr(),
+            value: S::shroud(self.value_ptr()),
+        }
+    }
+
+    /// Binds a new [`Lich`] to this [`Soul`] once fewer than `max` [`Lich`]es
+    /// are bound to it.
+    ///
+    /// If `max` or more [`Lich`]es are bound, the current thread is blocked
+    /// until enough of them are dropped or redeemed. This allows a [`Soul`] to
+    /// act as a semaphore that throttles how many [`Lich`]es may be handed
+    /// out at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is `0`.
+    ///
+    /// # Deadlock
+    ///
+    /// If the calling thread holds [`Lich`]es that keep the count at `max`,
+    /// calling this method will deadlock.
+    ///
+    /// # Cost
+    ///
+    /// Enabling the `bounded` feature adds a waiter counter to every [`Soul`]
+    /// and makes every [`Lich`] drop use a `SeqCst` decrement followed by a
+    /// `SeqCst` load of that counter, such that a thread parked here is never
+    /// missed.
+    #[cfg(feature = "bounded")]
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn bind_bounded<S: Shroud<T> + ?Sized>(self: Pin<&Self>, max: u32) -> Lich<S> {
+        #[cfg(any(debug_assertions, feature = "hardened"))]
+        self.assert_unmoved();
+        increment_bounded(&self.count, max);
+        Lich {
+            count: self.count_ptr(),
+            value: S::shroud(self.value_ptr()),
+        }
+    }
+
+    /// Reserves `count` bindings on this [`Soul`] up front.
+    ///
+    /// The binding count is incremented once by `count` such that the
+    /// returned [`Reservation`] can later bind up to `count` [`Lich`]es
+    /// without touching the counter and without any possibility of exceeding
+    /// the maximum number of [`Lich`]es. Reserved bindings are counted by
+    /// [`bindings()`](Soul::bindings) until they are bound or the
+    /// [`Reservation`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of [`Lich`]es would be exceeded.
+    #[must_use = "the reservation is immediately released if not used"]
+    pub fn reserve(self: Pin<&Self>, count: u32) -> Reservation<'_, T> {
+        #[cfg(any(debug_assertions, feature = "hardened"))]
+        self.assert_unmoved();
+        increment(&self.count, count);
+        Reservation {
+            soul: self,
+            remaining: count,
+        }
+    }
+
     /// Returns `true` if the [`Lich`] is bound to this [`Soul`].
     #[must_use]
     pub fn is_bound<S: ?Sized>(&self, lich: &Lich<S>) -> bool {
         ptr::eq(&self.count, lich.count.as_ptr())
     }
 
+    /// Redeems all the [`Lich`]es of `liches` that are bound to this [`Soul`]
+    /// and passes the others to `unbound`.
+    ///
+    /// Returns the number of [`Lich`]es that were redeemed.
+    pub fn redeem_iter<S: ?Sized, I: IntoIterator<Item = Lich<S>>, F: FnMut(Lich<S>)>(
+        &self,
+        liches: I,
+        mut unbound: F,
+    ) -> usize {
+        let mut redeemed = 0;
+        for lich in liches {
+            if self.is_bound(&lich) {
+                lich.redeem();
+                redeemed += 1;
+            } else {
+                unbound(lich);
+            }
+        }
+        redeemed
+    }
+
     /// Returns the number of [`Lich`]es that are currently bound to this
     /// [`Soul`].
     ///
@@ -112,61 +340,181 @@ impl<T: ?Sized> Soul<T> {
     /// already been severed.
     #[must_use]
     pub fn bindings(&self) -> usize {
-        let raw = self.count.load(Ordering::Relaxed);
+        self.bindings_with(Ordering::Relaxed)
+    }
+
+    /// Returns the number of [`Lich`]es that are currently bound to this
+    /// [`Soul`], reading the count with the given `order`.
+    ///
+    /// [`Ordering::Relaxed`] only observes the count itself,
+    /// [`Ordering::Acquire`] additionally synchronizes with the [`Lich`]es that
+    /// were dropped or redeemed before the count was read, and
+    /// [`Ordering::SeqCst`] also takes part in the single total order of
+    /// sequentially consistent operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Ordering::Release`] or [`Ordering::AcqRel`],
+    /// which are not valid orderings for a load.
+    #[must_use]
+    pub fn bindings_with(&self, order: Ordering) -> usize {
+        let raw = self.count.value.load(order);
         // `SEVERED` (`u32::MAX`) is the severed sentinel; treat it as 0 live bindings.
         raw.wrapping_add(1).saturating_sub(1) as _
     }
 
+    /// Returns the highest number of [`Lich`]es that have ever been bound to
+    /// this [`Soul`] at the same time.
+    ///
+    /// # Cost
+    ///
+    /// Only available with the `high-water` feature. Enabling it adds a peak
+    /// counter to every [`Soul`] and a `Relaxed` `fetch_max` on that counter to
+    /// every binding and clone of a [`Lich`].
+    #[cfg(feature = "high-water")]
+    #[must_use]
+    pub fn peak_bindings(&self) -> usize {
+        self.count.peak.load(Ordering::Relaxed) as _
+    }
+
+    /// Returns a compact single line summary of this [`Soul`]'s state,
+    /// formatted as `Soul { bindings: 3, severed: false }`.
+    ///
+    /// The binding count is read once, when this method is called.
+    #[must_use]
+    pub fn describe(&self) -> impl fmt::Display {
+        Describe(self.count.value.load(Ordering::Relaxed))
+    }
+
     /// Ensures that all bindings to this [`Soul`] are severed, blocking the
     /// current thread until all bound [`Lich`]es are dropped, then returns
     /// the unpinned `S`.
     ///
+    /// # Blocking
+    ///
+    /// This is the blocking sever; it always succeeds but may wait
+    /// indefinitely. If blocking is not acceptable (for example in a signal
+    /// handler or an asynchronous task), use the non-blocking
+    /// [`Soul::try_sever`] instead.
+    ///
     /// # Panics
     ///
-    /// This method does not panic.  If blocking until all Liches are dropped
-    /// is not acceptable, use [`Soul::try_sever`] instead.
+    /// This method does not panic.
     ///
     /// # Deadlock
     ///
     /// If the calling thread holds the last [`Lich`] that keeps the count
     /// non-zero, calling this method will deadlock.
     pub fn sever<S: Deref<Target = Self>>(this: Pin<S>) -> S {
-        if sever::<true>(&this.count) {
-            // Safety: `sever::<true>` returned `true`, meaning all Liches have
-            // been dropped and the count has been atomically set to u32::MAX.
-            unsafe { Self::unpin(this) }
-        } else {
-            // `sever::<true>` loops until count == 0 and never returns false.
-            unreachable!()
+        Self::sever_count(this).0
+    }
+
+    /// Like [`sever()`](Soul::sever), but also returns the number of
+    /// [`Lich`]es that were bound when the severance started, as observed by
+    /// its first compare-exchange on the binding count.
+    ///
+    /// A count of `0` means that the [`Soul`] was severed without waiting
+    /// (or was already severed), while a greater count is the number of
+    /// [`Lich`]es that the current thread had to wait for (not counting those
+    /// bound while it waited).
+    ///
+    /// # Deadlock
+    ///
+    /// If the calling thread holds the last [`Lich`] that keeps the count
+    /// non-zero, calling this method will deadlock.
+    pub fn sever_count<S: Deref<Target = Self>>(this: Pin<S>) -> (S, u32) {
+        match sever::<true>(&this.count.value) {
+            // Safety: all Liches have been dropped and the count has been atomically
+            // set to u32::MAX.
+            (Severance::Performed, count) => (unsafe { Self::unpin(this) }, count),
+            (Severance::AlreadySevered, _) => (unsafe { Self::unpin(this) }, 0),
+            // `sever::<true>` loops until count == 0 and never returns `Blocked`.
+            (Severance::Blocked, _) => unreachable!(),
+        }
+    }
+
+    /// Blocks the current thread until no [`Lich`] is bound to this [`Soul`].
+    ///
+    /// Unlike [`sever()`](Soul::sever), this does not sever the [`Soul`]; new
+    /// [`Lich`]es can still be bound once this method returns, including by
+    /// other threads before it even returns.
+    ///
+    /// # Deadlock
+    ///
+    /// If the calling thread holds a [`Lich`] bound to this [`Soul`], calling
+    /// this method will deadlock.
+    pub fn wait_for_redemption(&self) {
+        loop {
+            match self.count.value.load(Ordering::Acquire) {
+                0 | SEVERED => break,
+                value => sync::wait(&self.count.value, value),
+            }
         }
     }
 
     /// Returns the unpinned [`Soul`] if all bindings to it are severed.
+    ///
+    /// # Blocking
+    ///
+    /// This is the non-blocking sever; it never waits. If [`Lich`]es are still
+    /// bound, the pinned [`Soul`] is returned back as `Err` and remains
+    /// usable. Use [`Soul::sever`] to wait for the [`Lich`]es instead, or
+    /// [`Soul::try_sever_detailed`] to also know whether this call performed
+    /// the severance.
     #[must_use = "if Err, the Soul has not been severed"]
     pub fn try_sever<S: Deref<Target = Self>>(this: Pin<S>) -> Result<S, Pin<S>> {
-        if sever::<false>(&this.count) {
-            // Safety: `sever::<false>` returned `true`, which means the CAS
-            // succeeded (count was 0) and no Liches are bound.  It is therefore
-            // safe to unpin the Soul.
-            Ok(unsafe { Self::unpin(this) })
-        } else {
-            Err(this)
+        Self::try_sever_detailed(this).into_result()
+    }
+
+    /// Like [`try_sever()`](Soul::try_sever), but reports whether this call
+    /// performed the severance or found the [`Soul`] already severed (such as
+    /// through another clone of a `Pin<Arc<Soul<T>>>`).
+    #[must_use = "if Blocked, the Soul has not been severed"]
+    pub fn try_sever_detailed<S: Deref<Target = Self>>(this: Pin<S>) -> SeverOutcome<S> {
+        match sever::<false>(&this.count.value).0 {
+            // Safety: the CAS succeeded (count was 0) and no Liches are bound.  It is
+            // therefore safe to unpin the Soul.
+            Severance::Performed => SeverOutcome::Performed(unsafe { Self::unpin(this) }),
+            // Safety: the count is `u32::MAX` and no Lich can be bound anymore.
+            Severance::AlreadySevered => SeverOutcome::AlreadySevered(unsafe { Self::unpin(this) }),
+            Severance::Blocked => SeverOutcome::Blocked(this),
         }
     }
 
     /// # Safety
     ///
     /// The caller must ensure that `sever` (the standalone free function in
-    /// this module) has returned `true` for this Soul's `count` field
+    /// this module) has not returned `Severance::Blocked` for this Soul's `count` field
     /// before calling this function.  That is, all bound [`Lich`]es must
     /// have been dropped and the `count` must have been atomically set to
     /// `u32::MAX`.
     unsafe fn unpin<S: Deref<Target = Self>>(this: Pin<S>) -> S {
-        debug_assert_eq!(this.bindings(), 0);
+        // The raw count is checked since `bindings` reports a severed count as 0.
+        #[cfg(feature = "hardened")]
+        assert_eq!(
+            this.count.value.load(Ordering::Relaxed),
+            SEVERED,
+            "`Soul` unpinned before it was severed"
+        );
+        #[cfg(not(feature = "hardened"))]
+        debug_assert_eq!(this.count.value.load(Ordering::Relaxed), SEVERED);
         // Safety: no `Lich`es are bound, the `Soul` can be unpinned.
         unsafe { Pin::into_inner_unchecked(this) }
     }
 
+    /// Panics if this [`Soul`] has moved since its first binding, which can only
+    /// happen if some `unsafe` code broke the pinning guarantee.
+    #[cfg(any(debug_assertions, feature = "hardened"))]
+    fn assert_unmoved(self: Pin<&Self>) {
+        let address = self.count_ptr().as_ptr() as usize;
+        if let Err(origin) =
+            self.origin
+                .compare_exchange(0, address, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            assert_eq!(origin, address, "`Soul` moved after it was bound");
+        }
+    }
+
     fn value_ptr(self: Pin<&Self>) -> NonNull<T> {
         // Safety: we use `addr_of!` to obtain a raw pointer to the field without
         // creating an intermediate reference, preserving the raw provenance that is
@@ -176,7 +524,7 @@ impl<T: ?Sized> Soul<T> {
         unsafe { NonNull::new_unchecked(addr_of!(self.value) as _) }
     }
 
-    fn count_ptr(self: Pin<&Self>) -> NonNull<AtomicU32> {
+    fn count_ptr(self: Pin<&Self>) -> NonNull<Count> {
         // Safety: we use `addr_of!` to obtain a raw pointer to the field without
         // creating an intermediate reference, preserving the raw provenance that is
         // required for a pointer that will outlive the current borrow.  Because
@@ -186,6 +534,33 @@ impl<T: ?Sized> Soul<T> {
     }
 }
 
+impl<T, const N: usize> Soul<[T; N]> {
+    /// Binds one [`Lich`] to each element of the array owned by this [`Soul`].
+    ///
+    /// The `i`-th [`Lich`] points to the `i`-th element, and all of them are
+    /// bound to this [`Soul`], such that it can not be dropped or severed
+    /// until every one of them is dropped. The binding count is incremented by
+    /// `N` in a single atomic operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum number of [`Lich`]es would be exceeded.
+    #[must_use = "the Liches are immediately dropped if not used"]
+    pub fn bind_each<S: Shroud<T> + ?Sized>(self: Pin<&Self>) -> [Lich<S>; N] {
+        #[cfg(any(debug_assertions, feature = "hardened"))]
+        self.assert_unmoved();
+        // An `N` that does not fit in a `u32` can only exceed the maximum.
+        increment(&self.count, u32::try_from(N).unwrap_or(u32::MAX));
+        let count = self.count_ptr();
+        let value = self.value_ptr().cast::<T>();
+        core::array::from_fn(|index| Lich {
+            count,
+            // Safety: `index < N` such that the pointer stays within the array.
+            value: S::shroud(unsafe { NonNull::new_unchecked(value.as_ptr().add(index)) }),
+        })
+    }
+}
+
 impl<T> From<T> for Soul<T> {
     fn from(value: T) -> Self {
         Self::new(value)
@@ -212,21 +587,233 @@ impl<T: ?Sized> Borrow<T> for Soul<T> {
     }
 }
 
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Soul<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `origin` is left out such that the output does not depend on the build
+        // profile nor expose an address.
+        f.debug_struct("Soul")
+            .field("_marker", &self._marker)
+            .field("count", &self.count)
+            .field("value", &&self.value)
+            .finish()
+    }
+}
+
 impl<T: ?Sized> Drop for Soul<T> {
     fn drop(&mut self) {
-        sever::<true>(&self.count);
+        sever::<true>(&self.count.value);
+    }
+}
+
+/// A [`Soul`] whose [`Drop`] aborts the process instead of blocking when
+/// [`Lich`]es are still bound to it.
+///
+/// Created with [`Soul::new_nonblocking`]. [`Lich`]es are bound through the
+/// pinned [`Soul`] returned by [`soul()`](NonBlockingSoul::soul).
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct NonBlockingSoul<T: ?Sized>(Soul<T>);
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> NonBlockingSoul<T> {
+    /// Returns the wrapped [`Soul`], pinned such that [`Lich`]es can be bound
+    /// to it.
+    pub fn soul(self: Pin<&Self>) -> Pin<&Soul<T>> {
+        // Safety: the `Soul` is never moved out of its wrapper, so it is pinned
+        // whenever the wrapper is.
+        unsafe { self.map_unchecked(|this| &this.0) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Deref for NonBlockingSoul<T> {
+    type Target = Soul<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Drop for NonBlockingSoul<T> {
+    fn drop(&mut self) {
+        // Once severed here, the drop of the wrapped `Soul` returns immediately.
+        if sever::<false>(&self.0.count.value).0 == Severance::Blocked {
+            std::eprintln!("non-blocking `Soul` dropped while `Lich`es are bound; aborting");
+            std::process::abort();
+        }
+    }
+}
+
+/// A set of bindings reserved on a [`Soul`] with
+/// [`reserve()`](Soul::reserve).
+///
+/// When dropped, the bindings that have not been used are released.
+pub struct Reservation<'a, T: ?Sized> {
+    soul: Pin<&'a Soul<T>>,
+    remaining: u32,
+}
+
+impl<T: ?Sized> Reservation<'_, T> {
+    /// Returns the number of bindings that remain in this [`Reservation`].
+    #[must_use]
+    pub const fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Binds a new [`Lich`] to the reserved [`Soul`] using one of the
+    /// reserved bindings.
+    ///
+    /// Returns `None` if all reserved bindings have been used.
+    #[must_use = "the Lich is immediately dropped if not used"]
+    pub fn bind<S: Shroud<T> + ?Sized>(&mut self) -> Option<Lich<S>> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        Some(Lich {
+            count: self.soul.count_ptr(),
+            value: S::shroud(self.soul.value_ptr()),
+        })
     }
 }
 
-fn sever<const FORCE: bool>(count: &AtomicU32) -> bool {
+impl<T: ?Sized> Drop for Reservation<'_, T> {
+    fn drop(&mut self) {
+        if self.remaining > 0 {
+            decrement(&self.soul.count, self.remaining);
+        }
+    }
+}
+
+/// A [`Lich`] that may not outlive the borrow of its [`Soul`], created with
+/// [`bind_scoped()`](Soul::bind_scoped).
+///
+/// It dereferences to the [`Soul`]'s value just like a [`Lich`], but the
+/// borrow checker forbids it from leaving the scope of the borrow, such that
+/// it can never block the [`Soul`]'s drop.
+pub struct ScopedLich<'a, S: ?Sized> {
+    lich: Lich<S>,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<S: ?Sized> ScopedLich<'_, S> {
+    /// Disposes of this [`ScopedLich`] and returns the number of [`Lich`]es
+    /// still bound to its [`Soul`].
+    ///
+    /// See [`Lich::redeem`].
+    pub fn redeem(self) -> usize {
+        self.lich.redeem()
+    }
+}
+
+impl<S: ?Sized> Clone for ScopedLich<'_, S> {
+    fn clone(&self) -> Self {
+        Self {
+            lich: self.lich.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S: ?Sized> Deref for ScopedLich<'_, S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lich
+    }
+}
+
+impl<S: ?Sized> AsRef<S> for ScopedLich<'_, S> {
+    fn as_ref(&self) -> &S {
+        &self.lich
+    }
+}
+
+impl<S: ?Sized> Borrow<S> for ScopedLich<'_, S> {
+    fn borrow(&self) -> &S {
+        &self.lich
+    }
+}
+
+impl<S: fmt::Debug + ?Sized> fmt::Debug for ScopedLich<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.lich, f)
+    }
+}
+
+struct Describe(u32);
+
+impl fmt::Display for Describe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severed = self.0 == SEVERED;
+        let bindings = if severed { 0 } else { self.0 };
+        write!(f, "Soul {{ bindings: {bindings}, severed: {severed} }}")
+    }
+}
+
+/// The outcome of [`Soul::try_sever_detailed`].
+#[derive(Debug)]
+pub enum SeverOutcome<S> {
+    /// This call severed the [`Soul`]; it is returned unpinned.
+    Performed(S),
+    /// The [`Soul`] had already been severed; it is returned unpinned.
+    AlreadySevered(S),
+    /// [`Lich`]es are still bound to the [`Soul`]; it is returned still
+    /// pinned.
+    Blocked(Pin<S>),
+}
+
+impl<S> SeverOutcome<S> {
+    /// Returns `true` if the [`Soul`] is severed, whether or not it was by
+    /// this call.
+    #[must_use]
+    pub const fn is_severed(&self) -> bool {
+        !matches!(self, Self::Blocked(_))
+    }
+
+    /// Converts this outcome into the result of
+    /// [`try_sever()`](Soul::try_sever).
+    pub fn into_result(self) -> Result<S, Pin<S>> {
+        match self {
+            Self::Performed(soul) | Self::AlreadySevered(soul) => Ok(soul),
+            Self::Blocked(soul) => Err(soul),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severance {
+    Performed,
+    AlreadySevered,
+    Blocked,
+}
+
+/// Severs the count, waiting for it to reach 0 if `FORCE` is set, and returns
+/// the outcome along with the count seen by the first compare-exchange.
+fn sever<const FORCE: bool>(count: &AtomicU32) -> (Severance, u32) {
+    let mut first = None;
     loop {
-        match count.compare_exchange(0, SEVERED, Ordering::Acquire, Ordering::Relaxed) {
+        let exchange = count.compare_exchange(0, SEVERED, Ordering::Acquire, Ordering::Relaxed);
+        let (Ok(value) | Err(value)) = exchange;
+        let first = *first.get_or_insert(value);
+        match exchange {
             // `compare_exchange(0, …)` returns `Ok(old_value)` only when `old_value == 0`,
             // so only `Ok(0)` can appear here. `Err(SEVERED)` means a concurrent `sever`
             // already completed; either way, the Soul is severed.
-            Ok(0) | Err(SEVERED) => break true,
+            Ok(0) => break (Severance::Performed, first),
+            Err(SEVERED) => break (Severance::AlreadySevered, first),
             Ok(value) | Err(value) if FORCE => sync::wait(count, value),
-            Ok(_) | Err(_) => break false,
+            Ok(_) | Err(_) => break (Severance::Blocked, first),
         }
     }
 }
+
+#[cfg(all(test, feature = "hardened"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "`Soul` unpinned before it was severed")]
+    fn unpin_panics_if_not_severed() {
+        let soul = core::pin::pin!(Soul::new(()));
+        let _soul = unsafe { Soul::unpin(soul) };
+    }
+}
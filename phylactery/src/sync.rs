@@ -6,8 +6,12 @@
  * memory-ordering bugs.
  */
 
+#[cfg(all(any(debug_assertions, feature = "hardened"), not(loom)))]
+pub(crate) use core::sync::atomic::AtomicUsize;
 #[cfg(not(loom))]
 pub(crate) use core::sync::atomic::{AtomicU32, Ordering};
+#[cfg(all(any(debug_assertions, feature = "hardened"), loom))]
+pub(crate) use loom::sync::atomic::AtomicUsize;
 #[cfg(loom)]
 pub(crate) use loom::sync::atomic::{AtomicU32, Ordering};
 
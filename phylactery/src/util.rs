@@ -0,0 +1,151 @@
+/*!
+ * Reusable helpers built on top of [`Soul`] and [`Lich`].
+ */
+
+use crate::{lich::Lich, shroud::Shroud, soul::Soul};
+use core::pin::Pin;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    task::{Wake, Waker},
+    thread::{self, JoinHandle},
+};
+
+/// Spawns `count` threads that each receive their index and their own [`Lich`]
+/// bound to `soul`.
+///
+/// Each [`Lich`] is dropped when its thread's `function` returns (unless it is
+/// moved elsewhere), such that dropping or severing the [`Soul`] afterwards
+/// blocks until every thread is done with it. Joining the returned handles is
+/// not required for safety, but it is the only way to retrieve the results of
+/// `function` and to propagate its panics.
+///
+/// # Panics
+///
+/// Panics if a thread can not be spawned or if the maximum number of
+/// [`Lich`]es is exceeded.
+pub fn broadcast<T, S, R, F>(soul: Pin<&Soul<T>>, count: usize, function: F) -> Vec<JoinHandle<R>>
+where
+    T: ?Sized,
+    S: Shroud<T> + ?Sized + 'static,
+    Lich<S>: Send,
+    R: Send + 'static,
+    F: FnOnce(Lich<S>, usize) -> R + Clone + Send + 'static,
+{
+    (0..count)
+        .map(|index| {
+            let lich = soul.bind::<S>();
+            let function = function.clone();
+            thread::spawn(move || function(lich, index))
+        })
+        .collect()
+}
+
+/// Creates a [`Waker`] that calls `lich` when woken.
+///
+/// This allows wake logic that borrows from a non-`'static` scope to be handed
+/// to an asynchronous runtime. Cloning the [`Waker`] shares the same [`Lich`]
+/// and dropping the last clone drops it.
+///
+/// Note that the [`Soul`] that `lich` is bound to can not be severed or
+/// dropped without blocking until every clone of the [`Waker`] is dropped,
+/// which includes the clones held by the runtime.
+pub fn waker(lich: Lich<dyn Fn() + Send + Sync>) -> Waker {
+    Waker::from(Arc::new(LichWaker(lich)))
+}
+
+struct LichWaker(Lich<dyn Fn() + Send + Sync>);
+
+impl Wake for LichWaker {
+    fn wake(self: Arc<Self>) {
+        (self.0)();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        (self.0)();
+    }
+}
+
+/// A queue of [`Lich`]es collected back during teardown, to be redeemed
+/// against their [`Soul`].
+///
+/// ```
+/// use phylactery::{Soul, util::RedeemQueue};
+///
+/// let soul = Box::pin(Soul::new(|| {}));
+/// let mut queue = RedeemQueue::new();
+/// queue.push(soul.as_ref().bind::<dyn Fn()>());
+/// queue.push(soul.as_ref().bind::<dyn Fn()>());
+/// for result in queue.drain_redeem(&soul) {
+///     assert!(result.is_ok());
+/// }
+/// assert_eq!(soul.bindings(), 0);
+/// ```
+pub struct RedeemQueue<S: ?Sized> {
+    liches: VecDeque<Lich<S>>,
+}
+
+impl<S: ?Sized> RedeemQueue<S> {
+    /// Creates an empty [`RedeemQueue`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            liches: VecDeque::new(),
+        }
+    }
+
+    /// Adds a [`Lich`] to the back of the queue.
+    pub fn push(&mut self, lich: Lich<S>) {
+        self.liches.push_back(lich);
+    }
+
+    /// Returns the number of [`Lich`]es in the queue.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.liches.len()
+    }
+
+    /// Returns `true` if the queue holds no [`Lich`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.liches.is_empty()
+    }
+
+    /// Redeems the [`Lich`]es of the queue in order, yielding the number of
+    /// [`Lich`]es still bound to `soul` after each redemption.
+    ///
+    /// The iteration stops at the first [`Lich`] that is not bound to `soul`,
+    /// which is yielded as an `Err`; the [`Lich`]es after it remain in the
+    /// queue. Dropping the iterator early also leaves the remaining [`Lich`]es
+    /// in the queue.
+    pub fn drain_redeem<'a, T: ?Sized>(
+        &'a mut self,
+        soul: &'a Soul<T>,
+    ) -> impl Iterator<Item = Result<usize, Lich<S>>> + 'a {
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let lich = self.liches.pop_front()?;
+            if soul.is_bound(&lich) {
+                Some(Ok(lich.redeem()))
+            } else {
+                done = true;
+                Some(Err(lich))
+            }
+        })
+    }
+}
+
+impl<S: ?Sized> Default for RedeemQueue<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: ?Sized> Extend<Lich<S>> for RedeemQueue<S> {
+    fn extend<I: IntoIterator<Item = Lich<S>>>(&mut self, liches: I) {
+        self.liches.extend(liches);
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(all(feature = "shroud", feature = "std", feature = "test-alloc"))]
+
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+    pin::pin,
+};
+use phylactery::Soul;
+use std::alloc::System;
+
+/// Counts the allocations and deallocations of the current thread such that
+/// tests running in parallel do not interfere with each other.
+struct Counter;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static DEALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        let _ = DEALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.dealloc(pointer, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+/// Returns the number of allocations and deallocations performed by
+/// `function`.
+fn count<T>(function: impl FnOnce() -> T) -> (T, usize, usize) {
+    let allocations = ALLOCATIONS.get();
+    let deallocations = DEALLOCATIONS.get();
+    let value = function();
+    (
+        value,
+        ALLOCATIONS.get() - allocations,
+        DEALLOCATIONS.get() - deallocations,
+    )
+}
+
+#[test]
+fn stack_pinned_cycle_does_not_allocate() {
+    let ((), allocations, deallocations) = count(|| {
+        let soul = pin!(Soul::new(|| 'a'));
+        let lich1 = soul.as_ref().bind::<dyn Fn() -> char>();
+        let lich2 = lich1.clone();
+        assert_eq!(lich1(), 'a');
+        assert!(soul.is_bound(&lich2));
+        assert_eq!(soul.bindings(), 2);
+        assert_eq!(lich2.redeem(), 1);
+        drop(lich1);
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(deallocations, 0);
+}
+
+#[test]
+fn sever_does_not_allocate() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let (soul, allocations, deallocations) = count(|| {
+        let lich = soul.as_ref().bind::<dyn Fn() -> char + Send + Sync>();
+        drop(lich);
+        Soul::sever(soul)
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(deallocations, 0);
+    assert_eq!(soul(), 'a');
+}
+
+#[test]
+fn boxed_cycle_allocates_once() {
+    let ((), allocations, deallocations) = count(|| {
+        let soul = Box::pin(Soul::new(|| 'a'));
+        let lich1 = soul.as_ref().bind::<dyn Fn() -> char>();
+        let lich2 = lich1.clone();
+        assert_eq!(lich1(), 'a');
+        assert_eq!(lich2.redeem(), 1);
+        drop(lich1);
+        drop(soul);
+    });
+    assert_eq!(allocations, 1);
+    assert_eq!(deallocations, 1);
+}
+
+#[test]
+fn arc_cycle_allocates_once() {
+    let ((), allocations, deallocations) = count(|| {
+        let soul = std::sync::Arc::pin(Soul::new(|| 'a'));
+        let lich = soul.as_ref().bind::<dyn Fn() -> char + Send + Sync>();
+        assert_eq!(lich(), 'a');
+        drop(lich);
+        drop(soul);
+    });
+    assert_eq!(allocations, 1);
+    assert_eq!(deallocations, 1);
+}
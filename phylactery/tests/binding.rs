@@ -1,7 +1,7 @@
 #![cfg(all(feature = "shroud", feature = "std"))]
 
-use core::{cell::RefCell, fmt, pin::pin, time::Duration};
-use phylactery::{Lich, Soul};
+use core::{cell::RefCell, fmt, pin::pin, ptr::NonNull, time::Duration};
+use phylactery::{Lich, Shroud, Soul};
 use std::{
     rc::Rc,
     sync::{
@@ -189,22 +189,21 @@ fn unwinds_on_different_threads() {
 // }
 
 #[test]
-fn lich_debug_shows_value_and_bindings() {
+fn lich_debug_forwards_to_inner() {
     let soul = Box::pin(Soul::new(42_i32));
     let lich = soul.as_ref().bind::<dyn fmt::Debug>();
-    let debug = format!("{lich:?}");
-    assert!(
-        debug.contains("Lich"),
-        "Debug output should contain 'Lich': {debug}"
-    );
-    assert!(
-        debug.contains("42"),
-        "Debug output should contain the value '42': {debug}"
-    );
-    assert!(
-        debug.contains("bindings"),
-        "Debug output should contain 'bindings': {debug}"
-    );
+    assert_eq!(format!("{lich:?}"), "42");
+    assert_eq!(format!("{:?}", Some(&lich)), "Some(42)");
+}
+
+#[test]
+fn lich_debug_binding_shows_bindings() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    let _clone = lich.clone();
+    let debug = format!("{:?}", lich.debug_binding());
+    assert!(debug.starts_with("Lich { value: 0x"), "{debug}");
+    assert!(debug.ends_with("bindings: 2 }"), "{debug}");
 }
 
 #[test]
@@ -357,3 +356,628 @@ fn bindings_after_sever_returns_zero() {
     // After sever, bindings() maps the SEVERED sentinel (u32::MAX) to 0.
     assert_eq!(soul.bindings(), 0);
 }
+
+#[test]
+fn can_clone_many_liches() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let lich = soul.as_ref().bind::<dyn Fn() -> char>();
+    let liches = lich.clone_many(3);
+    assert_eq!(liches.len(), 3);
+    assert_eq!(soul.bindings(), 4);
+    for lich in liches.iter() {
+        assert!(soul.is_bound(lich));
+        assert_eq!(lich(), 'a');
+    }
+    drop(liches);
+    assert_eq!(lich.redeem(), 0);
+}
+
+#[test]
+fn can_clone_zero_liches() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    assert!(lich.clone_many(0).is_empty());
+    assert_eq!(soul.bindings(), 1);
+}
+
+#[test]
+#[should_panic]
+fn clone_many_beyond_maximum_panics() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    let _liches = lich.clone_many(u32::MAX as usize);
+}
+
+#[test]
+#[cfg(feature = "high-water")]
+fn peak_bindings_tracks_maximum() {
+    let soul = Box::pin(Soul::new(|| {}));
+    assert_eq!(soul.peak_bindings(), 0);
+    let lich1 = soul.as_ref().bind::<dyn Fn()>();
+    let lich2 = lich1.clone();
+    let liches = lich2.clone_many(3);
+    assert_eq!(soul.peak_bindings(), 5);
+    drop(liches);
+    drop(lich2);
+    assert_eq!(soul.bindings(), 1);
+    assert_eq!(soul.peak_bindings(), 5);
+    let _lich3 = soul.as_ref().bind::<dyn Fn()>();
+    assert_eq!(soul.peak_bindings(), 5);
+    drop(lich1);
+}
+
+#[test]
+fn can_bind_through_pinned() {
+    let boxed = Box::pin(Soul::new(|| 'a'));
+    let arc = Arc::pin(Soul::new(|| 'b'));
+    let rc = Rc::pin(Soul::new(|| 'c'));
+    let stack = pin!(Soul::new(|| 'd'));
+    assert_eq!(Soul::pinned(&boxed).bind::<dyn Fn() -> char>()(), 'a');
+    assert_eq!(Soul::pinned(&arc).bind::<dyn Fn() -> char>()(), 'b');
+    assert_eq!(Soul::pinned(&rc).bind::<dyn Fn() -> char>()(), 'c');
+    assert_eq!(Soul::pinned(&stack).bind::<dyn Fn() -> char>()(), 'd');
+}
+
+#[test]
+fn liches_of_same_value_are_ptr_eq() {
+    let soul1 = pin!(Soul::new(|| 'a'));
+    let soul2 = pin!(Soul::new(|| 'a'));
+    let lich1 = soul1.as_ref().bind::<dyn Fn() -> char>();
+    let lich2 = soul1.as_ref().bind::<dyn Fn() -> char>();
+    let lich3 = soul2.as_ref().bind::<dyn Fn() -> char>();
+    assert!(lich1.ptr_eq(&lich1.clone()));
+    assert!(lich1.ptr_eq(&lich2));
+    assert!(!lich1.ptr_eq(&lich3));
+}
+
+#[test]
+fn can_try_into_value_unbound_soul() {
+    let soul = Box::pin(Soul::new(String::from("a")));
+    let lich = soul.as_ref().bind::<dyn fmt::Display>();
+    drop(lich);
+    assert_eq!(Soul::try_into_value(soul).ok().unwrap(), "a");
+}
+
+#[test]
+fn can_not_try_into_value_bound_soul() {
+    let soul = Box::pin(Soul::new(String::from("a")));
+    let lich = soul.as_ref().bind::<dyn fmt::Display>();
+    let soul = Soul::try_into_value(soul).err().unwrap();
+    assert_eq!(lich.redeem(), 0);
+    assert_eq!(Soul::try_into_value(soul).ok().unwrap(), "a");
+}
+
+#[test]
+fn sever_into_value_waits_for_liches() {
+    let soul = Box::pin(Soul::new(String::from("a")));
+    let lich = soul.as_ref().bind::<dyn fmt::Display + Send + Sync>();
+    let handle = spawn(move || {
+        sleep(Duration::from_millis(10));
+        assert_eq!(lich.to_string(), "a");
+    });
+    assert_eq!(Soul::sever_into_value(soul), "a");
+    handle.join().unwrap();
+}
+
+#[test]
+fn soul_debug_does_not_depend_on_build_profile() {
+    let soul = Box::pin(Soul::new(42_u8));
+    let _lich = soul.as_ref().bind::<dyn fmt::Debug>();
+    assert_eq!(
+        format!("{soul:?}"),
+        "Soul { _marker: PhantomPinned, count: 1, value: 42 }"
+    );
+}
+
+#[test]
+fn soul_describe_summarizes_state() {
+    let soul = Box::pin(Soul::new(|| {}));
+    assert_eq!(
+        soul.describe().to_string(),
+        "Soul { bindings: 0, severed: false }"
+    );
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    let liches = lich.clone_many(2);
+    let description = soul.describe();
+    drop(lich);
+    assert_eq!(
+        description.to_string(),
+        "Soul { bindings: 3, severed: false }"
+    );
+    drop(liches);
+    let soul = Soul::sever(soul);
+    assert_eq!(
+        soul.describe().to_string(),
+        "Soul { bindings: 0, severed: true }"
+    );
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "hardened"))]
+#[should_panic(expected = "`Soul` moved after it was bound")]
+fn bind_panics_if_soul_moved() {
+    let soul = Soul::new(|| {});
+    // Safety: this intentionally breaks the pinning contract, after the `Lich` is
+    // dropped, to verify that the move is detected.
+    drop(unsafe { core::pin::Pin::new_unchecked(&soul) }.bind::<dyn Fn()>());
+    let moved = Box::new(soul);
+    let _lich = unsafe { core::pin::Pin::new_unchecked(&*moved) }.bind::<dyn Fn()>();
+}
+
+#[test]
+fn can_project_to_str() {
+    for text in ["", "a", "héllo, 世界 🦀"] {
+        let soul = Box::pin(Soul::new(String::from(text)));
+        let lich = soul.as_ref().bind::<dyn AsRef<str>>();
+        let projection: Lich<str> = lich.project(AsRef::as_ref);
+        assert_eq!(&*projection, text);
+        assert_eq!(projection.len(), text.len());
+        assert_eq!(projection.chars().count(), text.chars().count());
+        assert!(soul.is_bound(&projection));
+        assert_eq!(soul.bindings(), 2);
+        assert_eq!(lich.redeem(), 1);
+        let clone = projection.clone();
+        assert!(clone.ptr_eq(&projection));
+        assert_eq!(&*clone, text);
+        assert_eq!(projection.redeem(), 1);
+        assert_eq!(clone.redeem(), 0);
+        assert_eq!(Soul::sever(soul).into_value(), text);
+    }
+}
+
+#[test]
+fn can_send_str_projection_to_thread() {
+    let soul = Box::pin(Soul::new(String::from("key")));
+    let lich = soul
+        .as_ref()
+        .bind::<dyn AsRef<str> + Sync>()
+        .project(AsRef::as_ref);
+    let handle = spawn(move || lich.to_uppercase());
+    assert_eq!(handle.join().unwrap(), "KEY");
+    assert_eq!(soul.bindings(), 0);
+}
+
+#[test]
+fn can_bind_from_reservation() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let mut reservation = soul.as_ref().reserve(2);
+    assert_eq!(soul.bindings(), 2);
+    assert_eq!(reservation.remaining(), 2);
+    let lich1 = reservation.bind::<dyn Fn() -> char>().unwrap();
+    let lich2 = reservation.bind::<dyn Fn() -> char + Send>().unwrap();
+    assert!(reservation.bind::<dyn Fn() -> char>().is_none());
+    assert_eq!(reservation.remaining(), 0);
+    drop(reservation);
+    assert_eq!(soul.bindings(), 2);
+    assert!(soul.is_bound(&lich1));
+    assert_eq!((lich1(), lich2()), ('a', 'a'));
+    assert_eq!(lich1.redeem(), 1);
+    assert_eq!(lich2.redeem(), 0);
+}
+
+#[test]
+fn dropping_reservation_releases_remaining_bindings() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    let mut reservation = soul.as_ref().reserve(3);
+    let reserved = reservation.bind::<dyn Fn()>().unwrap();
+    assert_eq!(soul.bindings(), 4);
+    drop(reservation);
+    assert_eq!(soul.bindings(), 2);
+    drop(reserved);
+    assert_eq!(lich.redeem(), 0);
+    assert!(Soul::try_sever(soul).is_ok());
+}
+
+#[test]
+fn dropping_reservation_wakes_sever_thread() {
+    let soul = Arc::pin(Soul::new(|| {}));
+    let reservation = soul.as_ref().reserve(1);
+    let clone = soul.clone();
+    let handle = spawn(move || drop(Soul::sever(clone)));
+    sleep(Duration::from_millis(30));
+    drop(reservation);
+    handle.join().unwrap();
+}
+
+#[test]
+#[should_panic]
+fn reserve_beyond_maximum_panics() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let _lich = soul.as_ref().bind::<dyn Fn()>();
+    let _reservation = soul.as_ref().reserve(u32::MAX - 1);
+}
+
+#[test]
+fn can_redeem_iter_bound_liches() {
+    let soul1 = Box::pin(Soul::new(|| 'a'));
+    let soul2 = Box::pin(Soul::new(|| 'b'));
+    let lich1 = soul1.as_ref().bind::<dyn Fn() -> char>();
+    let lich2 = soul2.as_ref().bind::<dyn Fn() -> char>();
+    let mut liches = lich1.clone_many(2);
+    liches.push(lich2);
+    liches.push(lich1);
+    let mut unbound = Vec::new();
+    assert_eq!(soul1.redeem_iter(liches, |lich| unbound.push(lich)), 3);
+    assert_eq!(soul1.bindings(), 0);
+    assert_eq!(unbound.len(), 1);
+    assert!(soul2.is_bound(&unbound[0]));
+    assert_eq!(soul2.redeem_iter(unbound, drop), 1);
+    assert_eq!(soul2.bindings(), 0);
+}
+
+#[test]
+#[cfg(feature = "bounded")]
+fn bind_bounded_does_not_block_below_maximum() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich1 = soul.as_ref().bind_bounded::<dyn Fn()>(2);
+    let lich2 = soul.as_ref().bind_bounded::<dyn Fn()>(2);
+    assert_eq!(soul.bindings(), 2);
+    drop(lich1);
+    let _lich3 = soul.as_ref().bind_bounded::<dyn Fn()>(2);
+    assert_eq!(lich2.bindings(), 2);
+}
+
+#[test]
+#[cfg(feature = "bounded")]
+fn bind_bounded_blocks_until_lich_drops() {
+    use std::sync::mpsc;
+
+    let soul = Arc::pin(Soul::new(|| {}));
+    let lich1 = soul.as_ref().bind::<dyn Fn() + Sync>();
+    let lich2 = soul.as_ref().bind::<dyn Fn() + Sync>();
+    let clone = soul.clone();
+    let (sender, receiver) = mpsc::channel();
+    let handle = spawn(move || {
+        let lich = clone.as_ref().bind_bounded::<dyn Fn() + Sync>(2);
+        sender.send(lich.bindings()).unwrap();
+    });
+    assert!(
+        receiver.recv_timeout(Duration::from_millis(50)).is_err(),
+        "bind_bounded must block while the maximum is reached"
+    );
+    drop(lich1);
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(2));
+    handle.join().unwrap();
+    drop(lich2);
+    assert_eq!(soul.bindings(), 0);
+}
+
+#[test]
+#[cfg(feature = "bounded")]
+#[should_panic]
+fn bind_bounded_with_zero_maximum_panics() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let _lich = soul.as_ref().bind_bounded::<dyn Fn()>(0);
+}
+
+#[test]
+#[cfg(feature = "bounded")]
+#[should_panic(expected = "severed")]
+fn bind_bounded_on_severed_soul_panics() {
+    let soul = Arc::pin(Soul::new(|| {}));
+    let clone = soul.clone();
+    assert!(Soul::try_sever_detailed(soul).is_severed());
+    let _lich = clone.as_ref().bind_bounded::<dyn Fn()>(1);
+}
+
+#[test]
+fn nonblocking_soul_drops_without_liches() {
+    let soul = Box::pin(Soul::new_nonblocking(|| 'a'));
+    let lich = soul.as_ref().soul().bind::<dyn Fn() -> char>();
+    assert_eq!(lich(), 'a');
+    assert_eq!(soul.bindings(), 1);
+    drop(lich);
+    drop(soul);
+}
+
+#[test]
+fn nonblocking_soul_adds_no_state_to_soul() {
+    use core::mem::size_of;
+    use phylactery::NonBlockingSoul;
+
+    assert_eq!(size_of::<NonBlockingSoul<u8>>(), size_of::<Soul<u8>>());
+    let soul: core::pin::Pin<Box<NonBlockingSoul<dyn Fn() -> char>>> =
+        Box::pin(Soul::new_nonblocking(|| 'b'));
+    let lich = soul.as_ref().soul().bind::<dyn Fn() -> char>();
+    assert!(soul.is_bound(&lich));
+    assert_eq!(lich.redeem(), 0);
+}
+
+#[test]
+fn downcast_any_send_sync() {
+    use core::any::Any;
+    use std::collections::HashMap;
+
+    let number = Box::pin(Soul::new(42_u32));
+    let text = Box::pin(Soul::new(String::from("boba")));
+    let mut map = HashMap::<&str, Lich<dyn Any + Send + Sync>>::new();
+    map.insert("number", number.as_ref().bind());
+    map.insert("text", text.as_ref().bind());
+
+    let mut map = spawn(move || {
+        assert_eq!(map["number"].downcast_ref::<u32>(), Some(&42));
+        assert_eq!(map["text"].downcast_ref::<String>().unwrap(), "boba");
+        assert!(map["text"].downcast_ref::<u32>().is_none());
+        map
+    })
+    .join()
+    .unwrap();
+
+    let number_lich = map.remove("number").unwrap().downcast::<u32>().unwrap();
+    assert_eq!(*number_lich, 42);
+    assert_eq!(number.bindings(), 1);
+    let text_lich = map.remove("text").unwrap().downcast::<u32>().unwrap_err();
+    assert_eq!(text_lich.bindings(), 1);
+}
+
+#[test]
+fn bindings_with_ordering() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let lich = soul.as_ref().bind::<dyn Fn() + Send + Sync>();
+    for order in [Ordering::Relaxed, Ordering::Acquire, Ordering::SeqCst] {
+        assert_eq!(soul.bindings_with(order), 1);
+    }
+    spawn(move || drop(lich)).join().unwrap();
+    assert_eq!(soul.bindings_with(Ordering::Acquire), 0);
+}
+
+#[test]
+#[should_panic]
+fn bindings_with_release_panics() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let _ = soul.bindings_with(Ordering::Release);
+}
+
+#[test]
+fn can_map_to_field() {
+    struct Outer {
+        inner: (u8, String),
+    }
+
+    impl Shroud<Outer> for Outer {
+        fn shroud(from: NonNull<Outer>) -> NonNull<Self> {
+            from
+        }
+    }
+
+    let soul = Box::pin(Soul::new(Outer {
+        inner: (1, String::from("boba")),
+    }));
+    let lich = soul.as_ref().bind::<Outer>();
+    assert_eq!(lich.map(|outer| &outer.inner.1), "boba");
+    assert_eq!(*lich.map(|outer| &outer.inner.0), 1);
+    assert_eq!(lich.map(|outer| outer.inner.1.as_str()).len(), 4);
+    assert_eq!(soul.bindings(), 1);
+}
+
+#[test]
+fn any_lich_erases_value_type() {
+    use phylactery::AnyLich;
+
+    let function = Box::pin(Soul::new(|| 'a'));
+    let text = Box::pin(Soul::new(String::from("boba")));
+    let mut liches: Vec<Box<dyn AnyLich + Send>> = vec![
+        Box::new(function.as_ref().bind::<dyn Fn() -> char + Send + Sync>()),
+        Box::new(text.as_ref().bind::<dyn fmt::Display + Send + Sync>()),
+        Box::new(text.as_ref().bind::<dyn fmt::Debug + Send + Sync>()),
+    ];
+    assert_eq!(liches[0].bindings(), 1);
+    assert_eq!(liches[1].bindings(), 2);
+
+    let liches = spawn(move || {
+        let last = liches.pop().unwrap();
+        assert_eq!(last.redeem(), 1);
+        liches
+    })
+    .join()
+    .unwrap();
+    let redeemed = liches.into_iter().map(AnyLich::redeem).collect::<Vec<_>>();
+    assert_eq!(redeemed, [0, 0]);
+    assert_eq!(Soul::sever(function)(), 'a');
+    assert_eq!(Soul::sever(text).into_value(), "boba");
+}
+
+#[test]
+fn can_bind_unsized_soul() {
+    let soul: core::pin::Pin<Box<Soul<dyn Fn() -> String + Send + Sync>>> =
+        Box::pin(Soul::new(|| String::from("boba")));
+    let lich = soul.as_ref().bind::<dyn Fn() -> String + Send + Sync>();
+    spawn(move || assert_eq!(lich(), "boba")).join().unwrap();
+    assert_eq!(Soul::sever(soul)(), "boba");
+}
+
+#[test]
+fn wait_for_redemption_blocks_until_liches_drop() {
+    let soul = Box::pin(Soul::new(|| {}));
+    soul.wait_for_redemption();
+    let lich = soul.as_ref().bind::<dyn Fn() + Send + Sync>();
+    let clone = lich.clone();
+    let redeemed = Arc::new(AtomicBool::new(false));
+    let handle = spawn({
+        let redeemed = redeemed.clone();
+        move || {
+            sleep(Duration::from_millis(10));
+            redeemed.store(true, Ordering::Relaxed);
+            drop(lich);
+            drop(clone);
+        }
+    });
+    soul.wait_for_redemption();
+    assert!(redeemed.load(Ordering::Relaxed));
+    assert_eq!(soul.bindings(), 0);
+    handle.join().unwrap();
+
+    // The soul is not severed and can still be bound.
+    let lich = soul.as_ref().bind::<dyn Fn()>();
+    assert_eq!(lich.redeem(), 0);
+}
+
+#[test]
+fn bind_each_binds_distinct_elements() {
+    let buffers = [vec![1_u8], vec![2, 2], vec![3, 3, 3]];
+    let soul = Box::pin(Soul::new([&buffers[0], &buffers[1], &buffers[2]]));
+    let liches = soul
+        .as_ref()
+        .bind_each::<dyn AsRef<Vec<u8>> + Send + Sync>();
+    assert_eq!(soul.bindings(), 3);
+    let handles = liches.map(|lich| spawn(move || lich.as_ref().as_ref().len()));
+    let lengths = handles.map(|handle| handle.join().unwrap());
+    assert_eq!(lengths, [1, 2, 3]);
+    assert_eq!(soul.bindings(), 0);
+
+    let soul = Box::pin(Soul::new(['a', 'b']));
+    let [a, b] = soul.as_ref().bind_each::<dyn fmt::Display>();
+    assert_eq!(format!("{a}{b}"), "ab");
+    assert!(soul.is_bound(&a));
+    assert!(!a.ptr_eq(&soul.as_ref().bind_each::<dyn fmt::Display>()[1]));
+}
+
+#[test]
+fn into_any_round_trip_preserves_binding() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let lich = soul.as_ref().bind::<dyn Fn() -> char + Send + Sync>();
+    let services = vec![lich.clone().into_any(), Box::new(1_u8) as Box<_>];
+    assert_eq!(soul.bindings(), 2);
+
+    let mut services = services.into_iter();
+    let any = services.next().unwrap();
+    let any = Lich::<dyn Fn() -> char>::from_any(any).err().unwrap();
+    let clone = Lich::<dyn Fn() -> char + Send + Sync>::from_any(any)
+        .ok()
+        .unwrap();
+    assert_eq!(clone(), 'a');
+    assert!(clone.ptr_eq(&lich));
+    assert!(Lich::<dyn Fn() -> char + Send + Sync>::from_any(services.next().unwrap()).is_err());
+
+    drop(clone);
+    assert_eq!(soul.bindings(), 1);
+    drop(lich.into_any());
+    assert_eq!(soul.bindings(), 0);
+}
+
+#[test]
+fn try_sever_detailed_reports_outcome() {
+    use phylactery::SeverOutcome;
+
+    let soul = Arc::pin(Soul::new(42_u8));
+    let clone = soul.clone();
+    let lich = soul.as_ref().bind::<dyn fmt::Debug>();
+    let soul = match Soul::try_sever_detailed(soul) {
+        SeverOutcome::Blocked(soul) => soul,
+        outcome => panic!("expected `Blocked`, got {outcome:?}"),
+    };
+    drop(lich);
+    let outcome = Soul::try_sever_detailed(soul);
+    assert!(outcome.is_severed());
+    assert!(matches!(outcome, SeverOutcome::Performed(_)));
+    assert!(matches!(
+        Soul::try_sever_detailed(clone),
+        SeverOutcome::AlreadySevered(_)
+    ));
+}
+
+#[test]
+fn bind_ordered_publishes_prior_writes() {
+    use core::sync::atomic::AtomicU32;
+
+    let soul = Arc::pin(Soul::new(|| {}));
+    let data = Arc::new(AtomicU32::new(0));
+    let handle = spawn({
+        let soul = soul.clone();
+        let data = data.clone();
+        move || {
+            data.store(42, Ordering::Relaxed);
+            soul.as_ref()
+                .bind_ordered::<dyn Fn() + Send + Sync>(Ordering::Release)
+        }
+    });
+    while soul.bindings_with(Ordering::Acquire) == 0 {
+        std::hint::spin_loop();
+    }
+    assert_eq!(data.load(Ordering::Relaxed), 42);
+    let lich = handle.join().unwrap();
+    for order in [
+        Ordering::Relaxed,
+        Ordering::Acquire,
+        Ordering::AcqRel,
+        Ordering::SeqCst,
+    ] {
+        drop(soul.as_ref().bind_ordered::<dyn Fn()>(order));
+    }
+    assert_eq!(lich.redeem(), 0);
+}
+
+#[test]
+fn sever_count_returns_outstanding_liches() {
+    let soul = Box::pin(Soul::new(|| {}));
+    let (soul, count) = Soul::sever_count(soul);
+    assert_eq!(count, 0);
+
+    let soul = Box::pin(Soul::new(soul.into_value()));
+    let liches = soul.as_ref().bind::<dyn Fn() + Send + Sync>().clone_many(3);
+    assert_eq!(soul.bindings(), 3);
+    let handle = spawn(move || drop(liches));
+    // The liches may be dropped before or while `sever_count` observes the count.
+    let (soul, count) = Soul::sever_count(soul);
+    assert!(count <= 3, "{count}");
+    assert_eq!(soul.bindings(), 0);
+    handle.join().unwrap();
+}
+
+#[test]
+fn sever_count_of_severed_soul_is_zero() {
+    let soul = Arc::pin(Soul::new(|| {}));
+    let clone = soul.clone();
+    let lich = clone.as_ref().bind::<dyn Fn() + Send + Sync>();
+    assert_eq!(soul.bindings(), 1);
+    let handle = spawn(move || drop(lich));
+    assert!(Soul::sever_count(soul).1 <= 1);
+    assert_eq!(Soul::sever_count(clone).1, 0);
+    handle.join().unwrap();
+}
+
+#[test]
+fn scoped_lich_shares_within_scope() {
+    let soul = pin!(Soul::new(String::from("boba")));
+    let lich = soul.as_ref().bind_scoped::<dyn fmt::Display + Sync>();
+    let clone = lich.clone();
+    assert_eq!(soul.bindings(), 2);
+    std::thread::scope(|scope| {
+        scope.spawn(|| assert_eq!(clone.to_string(), "boba"));
+    });
+    assert_eq!(format!("{}", &*lich), "boba");
+    assert_eq!(lich.redeem(), 1);
+    drop(clone);
+    assert_eq!(soul.bindings(), 0);
+}
+
+#[test]
+fn drop_hook_runs_once_before_redeem() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let hooked = soul
+        .as_ref()
+        .bind::<dyn Fn() -> char + Send + Sync>()
+        .with_drop_hook({
+            let calls = calls.clone();
+            let observer = soul.as_ref().bind::<dyn Fn() -> char + Send + Sync>();
+            move || calls.lock().unwrap().push(observer.bindings())
+        });
+    assert_eq!(hooked(), 'a');
+    let clone = hooked.lich().clone();
+    assert_eq!(soul.bindings(), 3);
+    drop(clone);
+    assert!(calls.lock().unwrap().is_empty());
+    spawn(move || drop(hooked)).join().unwrap();
+    // The hook observed its own `Lich` and the one it captured still bound.
+    assert_eq!(*calls.lock().unwrap(), [2]);
+    assert_eq!(soul.bindings(), 0);
+
+    let hooked = soul
+        .as_ref()
+        .bind::<dyn Fn() -> char>()
+        .with_drop_hook(|| panic!());
+    let (lich, _) = hooked.unhook();
+    assert_eq!(lich.redeem(), 0);
+}
@@ -0,0 +1,39 @@
+#![cfg(all(feature = "shroud", feature = "std"))]
+
+use core::pin::pin;
+use phylactery::{MaybeLich, Soul};
+use std::thread::spawn;
+
+fn call(function: &MaybeLich<dyn Fn() -> char + Send + Sync>) -> char {
+    function()
+}
+
+#[test]
+fn can_deref_lich() {
+    let soul = pin!(Soul::new(|| 'a'));
+    let maybe = MaybeLich::from(soul.as_ref().bind::<dyn Fn() -> char + Send + Sync>());
+    assert!(maybe.is_lich());
+    assert_eq!(call(&maybe), 'a');
+    assert_eq!(soul.bindings(), 1);
+}
+
+#[test]
+fn can_deref_owned() {
+    let maybe = MaybeLich::<dyn Fn() -> char + Send + Sync>::from(
+        Box::new(|| 'b') as Box<dyn Fn() -> char + Send + Sync>
+    );
+    assert!(maybe.is_owned());
+    assert_eq!(call(&maybe), 'b');
+}
+
+#[test]
+fn can_send_to_thread() {
+    let soul = pin!(Soul::new(|| 'a'));
+    let maybes: [MaybeLich<dyn Fn() -> char + Send + Sync>; 2] = [
+        MaybeLich::Lich(soul.as_ref().bind()),
+        MaybeLich::Owned(Box::new(|| 'b')),
+    ];
+    let handle = spawn(move || maybes.iter().map(call).collect::<String>());
+    assert_eq!(handle.join().unwrap(), "ab");
+    assert_eq!(soul.bindings(), 0);
+}
@@ -0,0 +1,247 @@
+//! Exercises the pointer casts of every shroud path such that running this
+//! suite under Miri (`cargo +nightly miri test --test miri`) catches provenance
+//! and aliasing issues in them. The tests are single threaded and cheap to keep
+//! Miri fast.
+#![cfg(all(feature = "shroud", feature = "std"))]
+
+use core::{
+    any::Any,
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Display, LowerHex},
+    hash::{BuildHasher, Hasher},
+    iter::ExactSizeIterator,
+    ops::{Deref, Index},
+    pin::{Pin, pin},
+    ptr::NonNull,
+};
+use phylactery::{Lich, Shroud, Soul, shroud};
+use std::{collections::hash_map::RandomState, error::Error};
+
+/// Asserts that `lich` points to the value owned by `soul`, ignoring the
+/// pointer metadata added by the shroud.
+fn assert_points_to<T: ?Sized, S: ?Sized>(soul: Pin<&Soul<T>>, lich: &Lich<S>) {
+    let value: *const T = &**soul;
+    let shrouded: *const S = &**lich;
+    assert_eq!(value.cast::<()>(), shrouded.cast::<()>());
+    assert!(soul.is_bound(lich));
+}
+
+#[shroud(Send, Sync, ..)]
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+#[shroud]
+#[shroud(Self, Output = u32)]
+trait Produce<I> {
+    type Output;
+    fn produce(&self, input: I) -> Self::Output;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+impl Produce<u8> for Square {
+    type Output = u32;
+
+    fn produce(&self, input: u8) -> u32 {
+        u32::from(input) + 1
+    }
+}
+
+#[derive(Debug)]
+struct Failure;
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failure")
+    }
+}
+
+impl Error for Failure {}
+
+#[test]
+fn shrouds_functions() {
+    let soul = pin!(Soul::new(|left: u8, right: u8| left + right));
+    let lich = soul.as_ref().bind::<dyn Fn(u8, u8) -> u8>();
+    assert_points_to(soul.as_ref(), &lich);
+    assert_eq!(lich(1, 2), 3);
+    let lich = soul
+        .as_ref()
+        .bind::<dyn Fn(u8, u8) -> u8 + Send + Sync + Unpin>();
+    assert_eq!(lich(3, 4), 7);
+    let lich = soul.as_ref().bind::<dyn FnMut(u8, u8) -> u8>();
+    assert_points_to(soul.as_ref(), &lich);
+    let lich = soul.as_ref().bind::<dyn FnOnce(u8, u8) -> u8 + Sync>();
+    assert_points_to(soul.as_ref(), &lich);
+}
+
+#[test]
+fn shrouds_formatting_traits() {
+    let soul = pin!(Soul::new(255_u8));
+    let display = soul.as_ref().bind::<dyn Display>();
+    let debug = soul.as_ref().bind::<dyn Debug + Send + Sync>();
+    let hex = soul.as_ref().bind::<dyn LowerHex>();
+    let string = soul.as_ref().bind::<dyn ToString>();
+    assert_points_to(soul.as_ref(), &display);
+    assert_points_to(soul.as_ref(), &hex);
+    assert_eq!(format!("{display} {debug:?} {:x}", &*hex), "255 255 ff");
+    assert_eq!(string.to_string(), "255");
+    assert_eq!(soul.bindings(), 4);
+}
+
+#[test]
+fn shrouds_any() {
+    let soul = pin!(Soul::new(String::from("boba")));
+    let any = soul.as_ref().bind::<dyn Any>();
+    assert_points_to(soul.as_ref(), &any);
+    assert_eq!(any.downcast_ref::<String>().unwrap(), "boba");
+    assert!(any.downcast_ref::<&str>().is_none());
+    let string = any.downcast::<String>().ok().unwrap();
+    assert_points_to(soul.as_ref(), &string);
+    assert_eq!(string.len(), 4);
+
+    let any = soul.as_ref().bind::<dyn Any + Send + Sync>();
+    let any = Lich::<dyn Any + Send + Sync>::from_any(any.into_any())
+        .ok()
+        .unwrap();
+    assert_points_to(soul.as_ref(), &any);
+    assert_eq!(any.downcast::<String>().ok().unwrap().redeem(), 1);
+}
+
+#[test]
+fn shrouds_conversion_and_comparison_traits() {
+    let soul = pin!(Soul::new(String::from("b")));
+    let borrow = soul.as_ref().bind::<dyn Borrow<str>>();
+    let reference = soul.as_ref().bind::<dyn AsRef<[u8]> + Sync>();
+    let equal = soul.as_ref().bind::<dyn PartialEq<str>>();
+    let order = soul.as_ref().bind::<dyn PartialOrd<String>>();
+    assert_points_to(soul.as_ref(), &borrow);
+    assert_points_to(soul.as_ref(), &order);
+    assert_eq!((*borrow).borrow(), "b");
+    assert_eq!((*reference).as_ref(), b"b");
+    assert!(*equal == *"b");
+    assert_eq!(
+        order.partial_cmp(&String::from("a")),
+        Some(Ordering::Greater)
+    );
+}
+
+#[test]
+fn shrouds_traits_with_associated_types() {
+    let soul = pin!(Soul::new(vec![1_u16, 2, 3]));
+    let deref = soul.as_ref().bind::<dyn Deref<Target = [u16]>>();
+    let index = soul.as_ref().bind::<dyn Index<usize, Output = u16>>();
+    assert_points_to(soul.as_ref(), &deref);
+    assert_points_to(soul.as_ref(), &index);
+    assert_eq!(deref.len(), 3);
+    assert_eq!(index[2], 3);
+
+    let soul = pin!(Soul::new([1_u8, 2].into_iter()));
+    let iterator = soul.as_ref().bind::<dyn ExactSizeIterator<Item = u8>>();
+    assert_points_to(soul.as_ref(), &iterator);
+    assert_eq!(iterator.len(), 2);
+}
+
+#[test]
+fn shrouds_hashing_and_error_traits() {
+    let soul = pin!(Soul::new(RandomState::new()));
+    let build = soul
+        .as_ref()
+        .bind::<dyn BuildHasher<Hasher = std::collections::hash_map::DefaultHasher>>();
+    assert_points_to(soul.as_ref(), &build);
+    let mut left = build.build_hasher();
+    let mut right = build.build_hasher();
+    left.write_u8(1);
+    right.write_u8(1);
+    assert_eq!(left.finish(), right.finish());
+
+    let soul = pin!(Soul::new(Failure));
+    let error = soul.as_ref().bind::<dyn Error + Send + Sync>();
+    assert_points_to(soul.as_ref(), &error);
+    assert_eq!(error.to_string(), "failure");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn shrouds_user_traits() {
+    let soul = pin!(Soul::new(Square(2.0)));
+    let shape = soul.as_ref().bind::<dyn Shape>();
+    let shared = soul.as_ref().bind::<dyn Shape + Send + Sync>();
+    let produce = soul.as_ref().bind::<dyn Produce<u8, Output = u32>>();
+    assert_points_to(soul.as_ref(), &shape);
+    assert_points_to(soul.as_ref(), &produce);
+    assert_eq!(shape.area(), 4.0);
+    assert_eq!(shared.area(), 4.0);
+    assert_eq!(produce.produce(1), 2);
+
+    let pointer = NonNull::from(&**soul);
+    let produce = <dyn Produce<u8, Output = u32>>::shroud_from(pointer);
+    let produce = <dyn Produce<u8, Output = u32>>::shroud(produce);
+    assert_eq!(produce.cast::<()>(), pointer.cast::<()>());
+    assert_eq!(unsafe { produce.as_ref() }.produce(2), 3);
+}
+
+#[test]
+fn shrouds_identities() {
+    let soul = pin!(Soul::new([1_u32, 2, 3]));
+    let array = soul.as_ref().bind::<[u32; 3]>();
+    assert_points_to(soul.as_ref(), &array);
+    let slice = array.project(|array| &array[1..]);
+    assert_eq!(&*slice, [2, 3]);
+    let slice: Pin<&Soul<[u32]>> = soul.as_ref();
+    let slice = slice.bind::<[u32]>();
+    assert_eq!(slice.len(), 3);
+    let [first, .., last] = soul.as_ref().bind_each::<u32>();
+    assert_eq!((*first, *last), (1, 3));
+    assert!(soul.is_bound(&first));
+
+    let soul = pin!(Soul::new(String::from("héllo")));
+    let text = soul.as_ref().bind::<String>().project(String::as_str);
+    let text = text.project(|text| &text[1..]);
+    assert_eq!(&*text, "éllo");
+    assert!(soul.is_bound(&text));
+}
+
+#[test]
+fn shrouds_unsized_souls() {
+    let soul: Pin<Box<Soul<dyn Fn() -> u8>>> = Box::pin(Soul::new(|| 42));
+    let lich = soul.as_ref().bind::<dyn Fn() -> u8>();
+    assert_points_to(soul.as_ref(), &lich);
+    assert_eq!(lich(), 42);
+    drop(lich);
+    assert_eq!(Soul::sever(soul)(), 42);
+}
+
+#[test]
+fn binds_clones_and_redeems() {
+    let soul = Box::pin(Soul::new(|| 'a'));
+    let other = Box::pin(Soul::new(|| 'b'));
+    let lich = soul.as_ref().bind::<dyn Fn() -> char>();
+    let clones = lich.clone_many(3);
+    let mut reservation = soul.as_ref().reserve(2);
+    let reserved = reservation.bind::<dyn Fn() -> char>().unwrap();
+    drop(reservation);
+    assert_eq!(soul.bindings(), 5);
+    assert!(clones.iter().all(|clone| clone.ptr_eq(&lich)));
+    assert!(!other.is_bound(&lich));
+
+    let mut unbound = Vec::new();
+    let liches = clones
+        .into_iter()
+        .chain([other.as_ref().bind::<dyn Fn() -> char>()]);
+    assert_eq!(soul.redeem_iter(liches, |lich| unbound.push(lich)), 3);
+    assert_eq!(unbound.len(), 1);
+    assert_eq!(unbound.pop().unwrap()(), 'b');
+    assert_eq!(reserved.redeem(), 1);
+    assert_eq!(lich(), 'a');
+    assert_eq!(lich.redeem(), 0);
+    assert_eq!(Soul::sever(soul)(), 'a');
+    assert_eq!(Soul::try_sever(other).ok().unwrap()(), 'b');
+}
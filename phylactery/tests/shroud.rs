@@ -2,10 +2,11 @@
 
 use core::{
     fmt::{Debug, Display},
+    pin::pin,
     ptr::NonNull,
     str::FromStr,
 };
-use phylactery::{Shroud, shroud};
+use phylactery::{PushSink, Shroud, Soul, shroud};
 
 #[shroud]
 pub trait Simple {}
@@ -66,3 +67,182 @@ pub fn complex_compiles<
     <dyn Complex<T, U, N, A = C::A> + Send + Sync>::shroud(complex);
     <dyn Complex<T, U, N, A = C::A> + Sync>::shroud(complex);
 }
+
+pub fn shroud_from_compiles<
+    'a,
+    T: Debug,
+    U: FromStr + 'a,
+    const N: usize,
+    C: Complex<'a, T, U, N, A = usize> + Combine + Send + Sync + Unpin,
+>(
+    value: NonNull<C>,
+) where
+    for<'b> &'b T: Display,
+{
+    <dyn Simple>::shroud_from(value);
+    <dyn Combine + Sync + Send>::shroud_from(value);
+    <dyn Combine + Send + Sync + Unpin>::shroud_from(value);
+    <dyn Complex<T, U, N, A = usize> + Sync>::shroud_from(value);
+    <dyn Complex<T, U, N, A = usize>>::shroud_from(
+        value as NonNull<dyn Complex<T, U, N, A = usize>>,
+    );
+}
+
+pub fn push_sink_compiles<T, S: PushSink<T> + Send + Sync>(sink: NonNull<S>) {
+    <dyn PushSink<T>>::shroud(sink);
+    <dyn PushSink<T> + Send>::shroud(sink);
+    <dyn PushSink<T> + Send + Sync>::shroud(sink);
+}
+
+#[test]
+fn push_sink_extends_borrowed_collection() {
+    use core::cell::RefCell;
+
+    let soul = pin!(Soul::new(RefCell::new(String::new())));
+    let sink = soul.as_ref().bind::<dyn PushSink<char>>();
+    sink.push('a');
+    sink.push('b');
+    drop(sink);
+    assert_eq!(*soul.borrow(), "ab");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn push_sink_extends_collection_from_thread() {
+    use std::{sync::Mutex, thread::spawn};
+
+    let soul = Box::pin(Soul::new(Mutex::new(Vec::new())));
+    let sink = soul.as_ref().bind::<dyn PushSink<u8> + Send + Sync>();
+    spawn(move || (0..3).for_each(|item| sink.push(item)))
+        .join()
+        .unwrap();
+    assert_eq!(soul.bindings(), 0);
+    assert_eq!(*soul.lock().unwrap(), [0, 1, 2]);
+}
+
+#[shroud(Send, Sync, ..)]
+#[shroud(Self)]
+pub trait Sizable {
+    fn size(&self) -> usize;
+
+    fn into_size(self) -> usize
+    where
+        Self: Sized;
+
+    fn with_size<T, F: FnOnce(usize) -> T>(&self, function: F) -> T
+    where
+        Self: Sized,
+    {
+        function(self.size())
+    }
+}
+
+impl Sizable for [u8; 3] {
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn into_size(self) -> usize {
+        self.len()
+    }
+}
+
+pub fn sizable_compiles<S: Sizable + Send + Sync>(sizable: NonNull<S>) {
+    <dyn Sizable>::shroud(sizable);
+    <dyn Sizable + Send>::shroud(sizable);
+    <dyn Sizable + Send + Sync>::shroud(sizable);
+    <dyn Sizable>::shroud(sizable as NonNull<dyn Sizable>);
+}
+
+#[test]
+fn can_bind_trait_with_sized_methods() {
+    let soul = pin!(Soul::new([1u8, 2, 3]));
+    assert_eq!(soul.with_size(|size| size * 2), 6);
+    let lich = soul.as_ref().bind::<dyn Sizable + Send + Sync>();
+    assert_eq!(lich.size(), 3);
+}
+
+#[shroud(Send, Sync, Unpin, ..)]
+#[shroud(Self, Send, Sync, ..)]
+pub trait Converter<I, O> {
+    fn convert(&self, input: I) -> O;
+}
+
+impl Converter<u8, char> for char {
+    fn convert(&self, input: u8) -> char {
+        char::from(input).max(*self)
+    }
+}
+
+pub fn converter_compiles<I, O, C: Converter<I, O> + Send + Sync + Unpin>(converter: NonNull<C>) {
+    <dyn Converter<I, O>>::shroud(converter);
+    <dyn Converter<I, O> + Send>::shroud(converter);
+    <dyn Converter<I, O> + Sync>::shroud(converter);
+    <dyn Converter<I, O> + Unpin>::shroud(converter);
+    <dyn Converter<I, O> + Send + Sync>::shroud(converter);
+    <dyn Converter<I, O> + Send + Unpin>::shroud(converter);
+    <dyn Converter<I, O> + Sync + Unpin>::shroud(converter);
+    <dyn Converter<I, O> + Send + Sync + Unpin>::shroud(converter);
+
+    <dyn Converter<I, O>>::shroud(converter as NonNull<dyn Converter<I, O>>);
+    <dyn Converter<I, O> + Send>::shroud(converter as NonNull<dyn Converter<I, O> + Send>);
+    <dyn Converter<I, O> + Send + Sync>::shroud(
+        converter as NonNull<dyn Converter<I, O> + Send + Sync>,
+    );
+}
+
+#[test]
+fn can_bind_generic_trait_with_combinations() {
+    let soul = pin!(Soul::new('b'));
+    let lich = soul
+        .as_ref()
+        .bind::<dyn Converter<u8, char> + Send + Sync>();
+    assert_eq!(lich.convert(b'a'), 'b');
+    assert_eq!(lich.convert(b'c'), 'c');
+}
+
+#[test]
+fn identity_shroud_is_zero_cost_for_transparent_newtype() {
+    use core::mem::{align_of, size_of};
+    use phylactery::Lich;
+
+    #[repr(transparent)]
+    #[derive(Debug, PartialEq)]
+    struct Meters(f64);
+
+    impl Shroud<Meters> for Meters {
+        fn shroud(from: NonNull<Meters>) -> NonNull<Self> {
+            from
+        }
+    }
+
+    assert_eq!(size_of::<Meters>(), size_of::<f64>());
+    assert_eq!(align_of::<Meters>(), align_of::<f64>());
+    assert_eq!(size_of::<Soul<Meters>>(), size_of::<Soul<f64>>());
+    assert_eq!(size_of::<Lich<Meters>>(), size_of::<Lich<f64>>());
+    assert_eq!(size_of::<Lich<Meters>>(), 2 * size_of::<&Meters>());
+    assert_eq!(size_of::<Option<Lich<Meters>>>(), size_of::<Lich<Meters>>());
+
+    let meters = NonNull::from(&Meters(1.5));
+    assert_eq!(<Meters as Shroud<Meters>>::shroud(meters), meters);
+
+    let soul = pin!(Soul::new(Meters(1.5)));
+    let lich = soul.as_ref().bind::<Meters>();
+    assert_eq!(*lich, Meters(1.5));
+    assert!(core::ptr::eq(&*lich, &**soul));
+    let inner = lich.project(|meters| &meters.0);
+    assert_eq!(*inner, 1.5);
+    assert_eq!(
+        &*inner as *const f64 as *const Meters,
+        &*lich as *const Meters
+    );
+}
+
+#[test]
+fn identity_shroud_preserves_slice_length() {
+    let soul = pin!(Soul::new([1_u16, 2, 3]));
+    let slice: core::pin::Pin<&Soul<[u16]>> = soul.as_ref();
+    let lich = slice.bind::<[u16]>();
+    assert_eq!(&*lich, [1, 2, 3]);
+    assert_eq!(lich.as_ptr(), soul.as_ptr());
+}
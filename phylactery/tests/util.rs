@@ -0,0 +1,97 @@
+#![cfg(all(feature = "shroud", feature = "std"))]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use phylactery::{Lich, Soul, util::broadcast};
+
+#[test]
+fn broadcast_calls_function_on_all_threads() {
+    let total = AtomicUsize::new(0);
+    let soul = Box::pin(Soul::new(|index: usize| {
+        total.fetch_add(index, Ordering::Relaxed);
+    }));
+    let handles = broadcast(
+        soul.as_ref(),
+        4,
+        |lich: Lich<dyn Fn(usize) + Sync>, index| {
+            lich(index);
+            index
+        },
+    );
+    let indices = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [0, 1, 2, 3]);
+    assert_eq!(soul.bindings(), 0);
+    drop(soul);
+    assert_eq!(total.into_inner(), 6);
+}
+
+#[test]
+fn soul_drop_waits_for_broadcast_threads() {
+    let total = AtomicUsize::new(0);
+    let soul = Box::pin(Soul::new(|| {
+        total.fetch_add(1, Ordering::Relaxed);
+    }));
+    let handles = broadcast(soul.as_ref(), 8, |lich: Lich<dyn Fn() + Sync>, _| lich());
+    // Dropping the `Soul` blocks until every thread has dropped its `Lich`.
+    drop(soul);
+    assert_eq!(total.into_inner(), 8);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+#[test]
+fn waker_calls_lich() {
+    use phylactery::util::waker;
+
+    let wakes = AtomicUsize::new(0);
+    let soul = Box::pin(Soul::new(|| {
+        wakes.fetch_add(1, Ordering::Relaxed);
+    }));
+    let waker = waker(soul.as_ref().bind());
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    assert_eq!(soul.bindings(), 1);
+    std::thread::spawn(move || clone.wake()).join().unwrap();
+    waker.wake();
+    assert_eq!(soul.bindings(), 0);
+    drop(soul);
+    assert_eq!(wakes.into_inner(), 3);
+}
+
+#[test]
+fn redeem_queue_stops_at_first_mismatch() {
+    use phylactery::util::RedeemQueue;
+
+    let soul1 = Box::pin(Soul::new(|| 'a'));
+    let soul2 = Box::pin(Soul::new(|| 'b'));
+    let mut queue = RedeemQueue::<dyn Fn() -> char>::default();
+    queue.push(soul1.as_ref().bind());
+    queue.extend(soul1.as_ref().bind::<dyn Fn() -> char>().clone_many(2));
+    queue.push(soul2.as_ref().bind());
+    queue.push(soul1.as_ref().bind());
+    assert_eq!(queue.len(), 5);
+
+    let mut results = queue.drain_redeem(&soul1);
+    assert_eq!(results.next().unwrap().ok(), Some(3));
+    assert_eq!(results.next().unwrap().ok(), Some(2));
+    assert_eq!(results.next().unwrap().ok(), Some(1));
+    let mismatch = results.next().unwrap().err().unwrap();
+    assert!(results.next().is_none());
+    drop(results);
+    assert_eq!(mismatch(), 'b');
+    assert!(soul2.is_bound(&mismatch));
+    drop(mismatch);
+
+    assert_eq!(queue.len(), 1);
+    assert_eq!(
+        queue
+            .drain_redeem(&soul1)
+            .map(|result| result.ok())
+            .collect::<Vec<_>>(),
+        [Some(0)]
+    );
+    assert!(queue.is_empty());
+}
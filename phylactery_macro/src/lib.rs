@@ -1,8 +1,8 @@
 #![forbid(unsafe_code)]
 
 mod shroud;
-use crate::shroud::Shroud;
-use quote::{quote, quote_spanned};
+use crate::shroud::{string, Shroud};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
     parse_macro_input, ConstParam, GenericParam, Generics, ItemTrait, LifetimeParam, TraitItem,
     TraitItemType, TypeParam,
@@ -93,5 +93,41 @@ pub fn shroud(
                 )
             }
         });
-    quote! { #item #(#implementations)* }.into()
+    // Associated types are left generic such that a single inherent `impl` covers
+    // every assignment of them.
+    let generics = associates
+        .iter()
+        .map(|associate| format_ident!("__TAssociate{associate}__"))
+        .collect::<Vec<_>>();
+    let mut inherents = Vec::<(Vec<String>, _, _)>::new();
+    for shroud in &shrouds {
+        for paths in shroud.paths() {
+            // The same trait object type may be produced by multiple `#[shroud]`
+            // attributes (or by the same markers in a different order); its inherent
+            // `impl` must only be generated once.
+            let mut key = paths.iter().map(string).collect::<Vec<_>>();
+            key.sort();
+            if !inherents.iter().any(|(inherent, ..)| *inherent == key) {
+                inherents.push((key, shroud.span, paths));
+            }
+        }
+    }
+    let inherents = inherents.into_iter().map(|(_, span, paths)| {
+        quote_spanned!(span =>
+            impl<'__life__, #(#parameters,)* #(#generics,)*> dyn #ident<#(#parameter_names,)* #(#associates = #generics,)*> #(+ #paths)* + '__life__ #where_clause {
+                /// Shrouds the pointer `from` as this trait object.
+                ///
+                /// This is equivalent to [`Shroud::shroud`](::phylactery::Shroud::shroud).
+                #[inline(always)]
+                #[allow(dead_code)]
+                pub fn shroud_from<__TFrom__: ?Sized>(from: ::core::ptr::NonNull<__TFrom__>) -> ::core::ptr::NonNull<Self>
+                where
+                    Self: ::phylactery::Shroud<__TFrom__>,
+                {
+                    <Self as ::phylactery::Shroud<__TFrom__>>::shroud(from)
+                }
+            }
+        )
+    });
+    quote! { #item #(#implementations)* #(#inherents)* }.into()
 }
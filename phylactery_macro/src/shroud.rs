@@ -92,7 +92,7 @@ impl Shroud {
     }
 }
 
-fn string<T: ToTokens>(tokens: &T) -> String {
+pub fn string<T: ToTokens>(tokens: &T) -> String {
     tokens.to_token_stream().to_string()
 }
 